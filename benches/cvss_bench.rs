@@ -0,0 +1,131 @@
+//! Benchmarks for parsing and scoring CVSS vectors.
+//!
+//! Run with `cargo bench`. The `batch` group parses and scores 10,000 CVSS v4.0 vectors and is
+//! the best single indicator of end-to-end throughput.
+//!
+//! # Performance budget
+//!
+//! Baseline numbers were measured in release mode on a single core of a current x86_64
+//! machine. The budget leaves headroom for noise; exceeding it indicates a regression, e.g.
+//! from additional allocations in the per-component parsing loop.
+//!
+//! | Benchmark                  | Baseline | Budget   |
+//! |----------------------------|----------|----------|
+//! | `parse/*`                  | ~1.1 µs  | < 2 µs   |
+//! | `base_score/v2`            | ~6 ns    | < 50 ns  |
+//! | `base_score/v3_1`          | ~36 ns   | < 100 ns |
+//! | `base_score/v4`            | ~4.6 µs  | < 8 µs   |
+//! | `full_score/v2`            | ~21 ns   | < 50 ns  |
+//! | `full_score/v3_1`          | ~65 ns   | < 150 ns |
+//! | `full_score/v4`            | ~4.7 µs  | < 8 µs   |
+//! | `batch/v4_parse_and_score` | ~38 ms   | < 60 ms  |
+
+use std::hint::black_box;
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use cvss_rs::{v2_0::CvssV2, v3::CvssV3, v4_0::CvssV4, Cvss};
+
+const V2_VECTOR: &str = "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H";
+const V3_VECTOR: &str =
+    "CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:H/A:H/E:P/RL:O/RC:C/CR:H/IR:M/AR:L/MAV:A/MPR:N";
+const V4_VECTOR: &str =
+    "CVSS:4.0/AV:N/AC:L/AT:P/PR:L/UI:P/VC:H/VI:L/VA:N/SC:L/SI:N/SA:N/E:P/CR:H/MAV:A/MVI:H";
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("v2", |b| {
+        b.iter(|| CvssV2::from_str(black_box(V2_VECTOR)).unwrap())
+    });
+    group.bench_function("v3_1", |b| {
+        b.iter(|| CvssV3::from_str(black_box(V3_VECTOR)).unwrap())
+    });
+    group.bench_function("v4", |b| {
+        b.iter(|| CvssV4::from_str(black_box(V4_VECTOR)).unwrap())
+    });
+    group.bench_function("any", |b| {
+        b.iter(|| Cvss::from_str(black_box(V4_VECTOR)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_base_score(c: &mut Criterion) {
+    let v2 = CvssV2::from_str(V2_VECTOR).unwrap();
+    let v3 = CvssV3::from_str(V3_VECTOR).unwrap();
+    let v4 = CvssV4::from_str(V4_VECTOR).unwrap();
+
+    let mut group = c.benchmark_group("base_score");
+    group.bench_function("v2", |b| b.iter(|| black_box(&v2).calculated_base_score()));
+    group.bench_function("v3_1", |b| {
+        b.iter(|| black_box(&v3).calculated_base_score())
+    });
+    group.bench_function("v4", |b| b.iter(|| black_box(&v4).calculated_base_score()));
+    group.finish();
+}
+
+fn bench_full_score(c: &mut Criterion) {
+    let v2 = CvssV2::from_str(V2_VECTOR).unwrap();
+    let v3 = CvssV3::from_str(V3_VECTOR).unwrap();
+    let v4 = CvssV4::from_str(V4_VECTOR).unwrap();
+
+    let mut group = c.benchmark_group("full_score");
+    group.bench_function("v2", |b| {
+        b.iter(|| black_box(&v2).calculated_environmental_score())
+    });
+    group.bench_function("v3_1", |b| {
+        b.iter(|| black_box(&v3).calculated_environmental_score())
+    });
+    group.bench_function("v4", |b| b.iter(|| black_box(&v4).calculated_full_score()));
+    group.finish();
+}
+
+/// Builds `n` distinct, valid CVSS v4.0 vectors by cycling through metric values.
+fn v4_vectors(n: usize) -> Vec<String> {
+    const AV: [&str; 4] = ["N", "A", "L", "P"];
+    const PR: [&str; 3] = ["N", "L", "H"];
+    const UI: [&str; 3] = ["N", "P", "A"];
+    const IMPACT: [&str; 3] = ["H", "L", "N"];
+    const E: [&str; 4] = ["A", "P", "U", "X"];
+
+    (0..n)
+        .map(|i| {
+            format!(
+                "CVSS:4.0/AV:{}/AC:L/AT:N/PR:{}/UI:{}/VC:{}/VI:{}/VA:{}/SC:{}/SI:N/SA:N/E:{}",
+                AV[i % 4],
+                PR[(i / 4) % 3],
+                UI[(i / 12) % 3],
+                IMPACT[(i / 36) % 3],
+                IMPACT[(i / 108) % 3],
+                IMPACT[(i / 324) % 3],
+                IMPACT[(i / 972) % 3],
+                E[(i / 2916) % 4],
+            )
+        })
+        .collect()
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let vectors = v4_vectors(10_000);
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(vectors.len() as u64));
+    group.sample_size(20);
+    group.bench_function("v4_parse_and_score", |b| {
+        b.iter(|| {
+            vectors
+                .iter()
+                .filter_map(|v| CvssV4::from_str(v).ok()?.calculated_full_score())
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse,
+    bench_base_score,
+    bench_full_score,
+    bench_batch
+);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+use alloc::format;
+use alloc::string::String;
 use thiserror::Error;
 
 /// Errors that can occur when parsing CVSS vector strings.
@@ -16,18 +18,68 @@ pub enum ParseError {
     #[error("invalid or unsupported CVSS version: '{version}'")]
     InvalidPrefixVersion { version: String },
     /// Component is malformed (not in key:value format)
-    #[error("invalid component format: '{component}' (expected 'KEY:VALUE')")]
-    InvalidComponent { component: String },
+    #[error("invalid component format: '{component}' (expected 'KEY:VALUE'){}", at_offset(.position))]
+    InvalidComponent {
+        component: String,
+        position: Option<usize>,
+    },
     /// Metric abbreviation not recognized
-    #[error("unknown metric abbreviation: '{metric}'")]
-    UnknownMetric { metric: String },
+    #[error("unknown metric abbreviation: '{metric}'{}", at_offset(.position))]
+    UnknownMetric {
+        metric: String,
+        position: Option<usize>,
+    },
     /// Metric value parsing failed
-    #[error("invalid value '{value}' for metric '{metric}'")]
-    InvalidMetricValue { metric: String, value: String },
+    #[error("invalid value '{value}' for metric '{metric}'{}", at_offset(.position))]
+    InvalidMetricValue {
+        metric: String,
+        value: String,
+        position: Option<usize>,
+    },
     /// Required base metric is missing
     #[error("missing required metric: '{metric}'")]
     MissingRequiredMetric { metric: String },
     /// Same metric appears multiple times
-    #[error("duplicate metric: '{metric}'")]
-    DuplicateMetric { metric: String },
+    #[error("duplicate metric: '{metric}'{}", at_offset(.position))]
+    DuplicateMetric {
+        metric: String,
+        position: Option<usize>,
+    },
+    /// JSON object could not be deserialized into a CVSS object
+    #[error("invalid CVSS JSON object: {message}")]
+    InvalidJson { message: String },
+}
+
+impl ParseError {
+    /// Returns the byte offset of the offending component in the parsed vector string.
+    ///
+    /// Only set for component-level errors raised while parsing a vector string, not for errors
+    /// from e.g. [`CvssV3::from_metric_map`](crate::v3::CvssV3::from_metric_map).
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseError::InvalidComponent { position, .. }
+            | ParseError::UnknownMetric { position, .. }
+            | ParseError::InvalidMetricValue { position, .. }
+            | ParseError::DuplicateMetric { position, .. } => *position,
+            _ => None,
+        }
+    }
+
+    /// Sets the position of a component-level error, other errors are returned unchanged.
+    pub(crate) fn at(mut self, offset: usize) -> Self {
+        if let ParseError::InvalidComponent { position, .. }
+        | ParseError::UnknownMetric { position, .. }
+        | ParseError::InvalidMetricValue { position, .. }
+        | ParseError::DuplicateMetric { position, .. } = &mut self
+        {
+            *position = Some(offset);
+        }
+        self
+    }
+}
+
+fn at_offset(position: &Option<usize>) -> String {
+    position
+        .map(|offset| format!(" at offset {offset}"))
+        .unwrap_or_default()
 }
@@ -0,0 +1,158 @@
+//! Extracting CVSS data from CVE records.
+//!
+//! CVE records in the CVE JSON 5 format list the CVSS objects of a vulnerability in the
+//! `containers.cna.metrics` array, keyed by version, e.g. `[{"cvssV3_1": {...}}]`. Authorized
+//! Data Publishers (ADPs) such as CISA may add further CVSS objects in the `metrics` arrays of the
+//! `containers.adp` containers.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{v2_0::CvssV2, v3::CvssV3, v4_0::CvssV4, Cvss};
+
+#[derive(Deserialize)]
+struct CveRecord {
+    containers: Containers,
+}
+
+#[derive(Deserialize)]
+struct Containers {
+    cna: Container,
+    #[serde(default)]
+    adp: Vec<Container>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Container {
+    #[serde(default)]
+    provider_metadata: Option<ProviderMetadata>,
+    #[serde(default)]
+    metrics: Vec<Metric>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProviderMetadata {
+    short_name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Metric {
+    #[serde(rename = "cvssV4_0")]
+    cvss_v4_0: Option<Value>,
+    #[serde(rename = "cvssV3_1")]
+    cvss_v3_1: Option<Value>,
+    #[serde(rename = "cvssV3_0")]
+    cvss_v3_0: Option<Value>,
+    #[serde(rename = "cvssV2_0")]
+    cvss_v2_0: Option<Value>,
+}
+
+impl Container {
+    /// Deserializes all CVSS objects of the container, in document order.
+    fn scores(self) -> impl Iterator<Item = Result<Cvss, serde_json::Error>> {
+        self.metrics.into_iter().flat_map(|metric| {
+            [
+                metric
+                    .cvss_v4_0
+                    .map(|v| serde_json::from_value::<CvssV4>(v).map(Cvss::V4)),
+                metric
+                    .cvss_v3_1
+                    .map(|v| serde_json::from_value::<CvssV3>(v).map(Cvss::V3_1)),
+                metric
+                    .cvss_v3_0
+                    .map(|v| serde_json::from_value::<CvssV3>(v).map(Cvss::V3_0)),
+                metric
+                    .cvss_v2_0
+                    .map(|v| serde_json::from_value::<CvssV2>(v).map(Cvss::V2)),
+            ]
+            .into_iter()
+            .flatten()
+        })
+    }
+}
+
+impl CveRecord {
+    /// Deserializes all CVSS objects of the CNA container, in document order.
+    fn scores(self) -> impl Iterator<Item = Result<Cvss, serde_json::Error>> {
+        self.containers.cna.scores()
+    }
+
+    /// Deserializes all CVSS objects of the CNA container and then of the ADP containers, in
+    /// document order, labeled with their container.
+    fn sourced_scores(
+        self,
+    ) -> impl Iterator<Item = Result<(ScoreSource, Cvss), serde_json::Error>> {
+        let cna = self
+            .containers
+            .cna
+            .scores()
+            .map(|score| score.map(|cvss| (ScoreSource::Cna, cvss)));
+        let adp = self.containers.adp.into_iter().flat_map(|mut container| {
+            let source = ScoreSource::Adp {
+                short_name: container
+                    .provider_metadata
+                    .take()
+                    .and_then(|metadata| metadata.short_name),
+            };
+            container
+                .scores()
+                .map(move |score| score.map(|cvss| (source.clone(), cvss)))
+        });
+        cna.chain(adp)
+    }
+}
+
+/// The container of a CVE record that a CVSS object was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScoreSource {
+    /// The container of the CVE Numbering Authority (`containers.cna`).
+    Cna,
+    /// A container of an Authorized Data Publisher (`containers.adp`), with the short name of the
+    /// provider if present, e.g. `CISA-ADP`.
+    Adp { short_name: Option<String> },
+}
+
+/// Returns the CVSS objects of the `containers.cna.metrics` array of a CVE JSON 5 record.
+///
+/// The version of each object is taken from its key (`cvssV4_0`, `cvssV3_1`, `cvssV3_0` or
+/// `cvssV2_0`). Objects that cannot be deserialized are skipped, and a document that is not a
+/// CVE record yields no objects. Use [`try_scores_from_cve_json`] to detect these cases.
+pub fn scores_from_cve_json(bytes: &[u8]) -> Vec<Cvss> {
+    serde_json::from_slice::<CveRecord>(bytes)
+        .map(|record| record.scores().filter_map(Result::ok).collect())
+        .unwrap_or_default()
+}
+
+/// Like [`scores_from_cve_json`], but fails if the document is not a CVE record or any of its
+/// CVSS objects cannot be deserialized.
+pub fn try_scores_from_cve_json(bytes: &[u8]) -> Result<Vec<Cvss>, serde_json::Error> {
+    serde_json::from_slice::<CveRecord>(bytes)?
+        .scores()
+        .collect()
+}
+
+/// Returns the CVSS objects of the CNA container and then of the ADP containers of a CVE JSON 5
+/// record, each labeled with its container.
+///
+/// Like [`scores_from_cve_json`], objects that cannot be deserialized are skipped, and a
+/// document that is not a CVE record yields no objects. Use [`try_sourced_scores_from_cve_json`]
+/// to detect these cases.
+pub fn sourced_scores_from_cve_json(bytes: &[u8]) -> Vec<(ScoreSource, Cvss)> {
+    serde_json::from_slice::<CveRecord>(bytes)
+        .map(|record| record.sourced_scores().filter_map(Result::ok).collect())
+        .unwrap_or_default()
+}
+
+/// Like [`sourced_scores_from_cve_json`], but fails if the document is not a CVE record or any
+/// of its CVSS objects cannot be deserialized.
+pub fn try_sourced_scores_from_cve_json(
+    bytes: &[u8],
+) -> Result<Vec<(ScoreSource, Cvss)>, serde_json::Error> {
+    serde_json::from_slice::<CveRecord>(bytes)?
+        .sourced_scores()
+        .collect()
+}
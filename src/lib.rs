@@ -8,6 +8,8 @@
 //! Deserializing a CVSS v3.1 JSON object:
 //!
 //! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
 //! use cvss_rs::v3::AttackVector;
 //! use cvss_rs::{Cvss, Severity, Version};
 //!
@@ -39,16 +41,48 @@
 //!     // The example should panic if the if let fails
 //!     panic!("Expected Cvss::V3_1 variant");
 //! }
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
 //! ```
+//!
+//! # Features
+//!
+//! - `serde` (enabled by default): implements `Serialize` and `Deserialize` for the CVSS types,
+//!   and enables the JSON APIs, like [`Cvss::from_json_infer`] and the [`ingest`] and [`nvd`]
+//!   modules. Without it, vectors can still be parsed, formatted and scored.
+//! - `std` (enabled by default): links the standard library. Without it, the crate is `no_std`
+//!   and only needs `alloc`. The APIs that take a `HashMap`, like
+//!   [`CvssV3::from_metric_map`](v3::CvssV3::from_metric_map), are not available then.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use serde::Deserialize;
-use std::fmt::{Display, Formatter};
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::{Display, EnumDiscriminants, EnumString};
+use version::MetricGroup;
 
 pub mod error;
+#[cfg(feature = "serde")]
+pub mod ingest;
+#[cfg(feature = "serde")]
+pub mod nvd;
+pub mod prioritize;
 pub(crate) mod utils;
 pub mod v2_0;
 pub mod v3;
+pub mod v3_0;
+pub mod v3_1;
 pub mod v4_0;
 pub mod version;
 
@@ -56,33 +90,352 @@ pub mod version;
 pub use error::ParseError;
 
 /// An enum to hold any version of a CVSS object.
-#[derive(Debug, Deserialize, EnumDiscriminants)]
-#[serde(tag = "version")]
+///
+/// The version is read from the `version` field of the JSON object, so a `Cvss` can be embedded
+/// in a larger struct with `#[serde(flatten)]`, as long as that struct has no `version` field of
+/// its own. The other fields of the struct are ignored by the CVSS object.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # fn main() {
+/// use cvss_rs::{Cvss, Version};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Advisory {
+///     id: String,
+///     #[serde(flatten)]
+///     cvss: Cvss,
+/// }
+///
+/// let json_data = r#"{
+///   "id": "ADV-2024-0001",
+///   "version": "3.1",
+///   "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+///   "baseScore": 9.8,
+///   "baseSeverity": "CRITICAL"
+/// }"#;
+///
+/// let advisory: Advisory = serde_json::from_str(json_data).unwrap();
+///
+/// assert_eq!(advisory.id, "ADV-2024-0001");
+/// assert_eq!(advisory.cvss.version(), Version::V3_1);
+/// # }
+/// # #[cfg(not(feature = "serde"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, EnumDiscriminants)]
 #[strum_discriminants(name(Version))]
 #[strum_discriminants(vis(pub))]
 #[strum_discriminants(derive(Display, EnumString))]
 pub enum Cvss {
-    #[serde(rename = "2.0")]
-    #[strum_discriminants(strum(serialize = "2.0"))]
+    #[strum_discriminants(strum(to_string = "2.0", serialize = "V2"))]
     V2(v2_0::CvssV2),
-    #[serde(rename = "3.0")]
-    #[strum_discriminants(strum(serialize = "3.0"))]
+    #[strum_discriminants(strum(to_string = "3.0", serialize = "V3_0"))]
     V3_0(v3::CvssV3),
-    #[serde(rename = "3.1")]
-    #[strum_discriminants(strum(serialize = "3.1"))]
+    #[strum_discriminants(strum(to_string = "3.1", serialize = "V3_1"))]
     V3_1(v3::CvssV3),
-    #[serde(rename = "4.0")]
-    #[strum_discriminants(strum(serialize = "4.0"))]
+    #[strum_discriminants(strum(to_string = "4.0", serialize = "V4"))]
     V4(v4_0::CvssV4),
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cvss {
+    /// Deserializes the CVSS object of the version given by the `version` tag.
+    ///
+    /// The tag also sets the `version` field of [`v3::CvssV3`], which it replaces in the JSON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(tag = "version")]
+        enum Tagged {
+            #[serde(rename = "2.0")]
+            V2(v2_0::CvssV2),
+            #[serde(rename = "3.0")]
+            V3_0(v3::CvssV3),
+            #[serde(rename = "3.1")]
+            V3_1(v3::CvssV3),
+            #[serde(rename = "4.0")]
+            V4(v4_0::CvssV4),
+        }
+
+        let with_version = |c: v3::CvssV3, version| v3::CvssV3 {
+            version: Some(version),
+            ..c
+        };
+        Ok(match Tagged::deserialize(deserializer)? {
+            Tagged::V2(c) => Cvss::V2(c),
+            Tagged::V3_0(c) => Cvss::V3_0(with_version(c, version::VersionV3::V3_0)),
+            Tagged::V3_1(c) => Cvss::V3_1(with_version(c, version::VersionV3::V3_1)),
+            Tagged::V4(c) => Cvss::V4(c),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cvss {
+    /// Serializes the inner CVSS object with the `version` tag, as read by the `Deserialize`
+    /// implementation.
+    ///
+    /// The `version` field of [`v3::CvssV3`] is replaced by the tag, so it is not emitted twice.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(tag = "version")]
+        enum Tagged<'a> {
+            #[serde(rename = "2.0")]
+            V2(&'a v2_0::CvssV2),
+            #[serde(rename = "3.0")]
+            V3_0(&'a v3::CvssV3),
+            #[serde(rename = "3.1")]
+            V3_1(&'a v3::CvssV3),
+            #[serde(rename = "4.0")]
+            V4(&'a v4_0::CvssV4),
+        }
+
+        let without_version = |c: &v3::CvssV3| v3::CvssV3 {
+            version: None,
+            ..c.clone()
+        };
+        match self {
+            Cvss::V2(c) => Tagged::V2(c).serialize(serializer),
+            Cvss::V3_0(c) => Tagged::V3_0(&without_version(c)).serialize(serializer),
+            Cvss::V3_1(c) => Tagged::V3_1(&without_version(c)).serialize(serializer),
+            Cvss::V4(c) => Tagged::V4(c).serialize(serializer),
+        }
+    }
+}
+
 impl Display for Cvss {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.vector_string())
+    /// Formats the metrics as a vector string, see [`Cvss::to_vector_string`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.to_vector_string())
+    }
+}
+
+/// CVSS objects are compared by [`Cvss::sort_key`], i.e. by their calculated base scores, falling
+/// back to the stored base score if the vector is incomplete, and for equal scores by the recency
+/// of the version (v2.0 < v3.0 < v3.1 < v4.0). Ties are broken by their normalized vector
+/// strings (see [`Cvss::normalized_vector`]).
+///
+/// This compares the numeric scores, not the qualitative [`Severity`], so e.g. a 7.0 and a 8.9
+/// differ even though both are High. Objects are equal if they have the same version and
+/// metrics, even if their original vector strings list the metrics in a different order or
+/// write out Not Defined metrics, and regardless of their stored scores. Scores are compared
+/// with [`f64::total_cmp`], so the order is total and never panics, even for NaN.
+impl PartialEq for Cvss {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Cvss {}
+
+/// Hashes the normalized vector string (see [`Cvss::normalized_vector`]), so that a
+/// `HashSet<Cvss>` deduplicates semantically identical vectors.
+///
+/// The floating-point score fields are intentionally excluded from the hash.
+impl Hash for Cvss {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized_vector().hash(state);
+    }
+}
+
+impl PartialOrd for Cvss {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cvss {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key()
+            .cmp(&other.sort_key())
+            .then_with(|| self.normalized_vector().cmp(&other.normalized_vector()))
+    }
+}
+
+impl FromStr for Cvss {
+    type Err = ParseError;
+
+    /// Parses a vector string of any supported CVSS version.
+    ///
+    /// The version is detected from the `CVSS:X.Y` prefix only, never from the metric keys, so
+    /// abbreviations shared between versions (e.g. `AU` is Authentication in v2 but Automatable
+    /// in v4) are always interpreted by the parser of the prefixed version. Vectors without a
+    /// prefix are treated as CVSS v2.0, which is the only version where the prefix is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (version, _) = utils::prefix::extract_version_from_optional_prefix(s)?;
+
+        match version {
+            None | Some(Version::V2) => v2_0::CvssV2::from_str(s).map(Cvss::V2),
+            Some(Version::V3_0) => v3::CvssV3::from_str(s).map(Cvss::V3_0),
+            Some(Version::V3_1) => v3::CvssV3::from_str(s).map(Cvss::V3_1),
+            Some(Version::V4) => v4_0::CvssV4::from_str(s).map(Cvss::V4),
+        }
     }
 }
 
 impl Cvss {
+    /// Parses a vector string of any supported CVSS version without failing on invalid
+    /// components.
+    ///
+    /// Unlike [`Cvss::from_str`], which stops at the first error, every component is parsed and
+    /// all errors are collected, while the valid components populate the returned object. Only a
+    /// missing or invalid `CVSS:X.Y` prefix yields no object, as the version is unknown then.
+    pub fn parse_lossy(s: &str) -> (Option<Cvss>, Vec<ParseError>) {
+        let (version, components) = match utils::prefix::extract_version_from_optional_prefix(s) {
+            Ok(prefix) => prefix,
+            Err(err) => return (None, vec![err]),
+        };
+
+        let mut errors = Vec::new();
+        let mut parse = |set: &mut dyn FnMut(&str, &str) -> Result<(), ParseError>| {
+            let mut offset = s.len() - components.len();
+            for component in components.split('/') {
+                let position = offset;
+                offset += component.len() + 1;
+                if component.is_empty() {
+                    continue;
+                }
+
+                let result = utils::parse_metrics::split_component(component)
+                    .and_then(|(key, value)| set(&key, &value));
+                if let Err(err) = result {
+                    errors.push(err.at(position));
+                }
+            }
+        };
+
+        // the score pseudo-metrics populate the stored scores, but are not part of the vector
+        let vector_string = utils::parse_metrics::without_score_pseudo_metrics(s);
+        let cvss = match version {
+            None | Some(Version::V2) => {
+                let mut cvss = v2_0::CvssV2::empty(vector_string);
+                let mut scores = utils::parse_metrics::ScorePseudoMetrics::new();
+                parse(&mut |key, value| cvss.set_component(&mut scores, key, value));
+                cvss.set_scores(scores);
+                Cvss::V2(cvss)
+            }
+            Some(Version::V3_0) => {
+                let mut cvss = v3::CvssV3::empty(vector_string, version::VersionV3::V3_0);
+                let mut scores = utils::parse_metrics::ScorePseudoMetrics::new();
+                parse(&mut |key, value| cvss.set_component(&mut scores, key, value));
+                cvss.set_scores(scores);
+                Cvss::V3_0(cvss)
+            }
+            Some(Version::V3_1) => {
+                let mut cvss = v3::CvssV3::empty(vector_string, version::VersionV3::V3_1);
+                let mut scores = utils::parse_metrics::ScorePseudoMetrics::new();
+                parse(&mut |key, value| cvss.set_component(&mut scores, key, value));
+                cvss.set_scores(scores);
+                Cvss::V3_1(cvss)
+            }
+            Some(Version::V4) => {
+                let mut cvss = v4_0::CvssV4::empty(vector_string);
+                let mut scores = utils::parse_metrics::ScorePseudoMetrics::new();
+                parse(&mut |key, value| cvss.set_component(&mut scores, key, value));
+                cvss.set_scores(scores);
+                Cvss::V4(cvss)
+            }
+        };
+
+        (Some(cvss), errors)
+    }
+
+    /// Deserializes a CVSS JSON object, inferring the version from the prefix of its
+    /// `vectorString` instead of requiring a `version` field.
+    ///
+    /// Like [`Cvss::from_str`], vectors without a prefix are treated as CVSS v2.0. A `version`
+    /// field in the object, if any, is ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::InvalidJson`] if `vectorString` is missing or the object does not match
+    ///   the inferred version
+    /// * Prefix errors of the vector string, e.g. [`ParseError::InvalidPrefixVersion`]
+    #[cfg(feature = "serde")]
+    pub fn from_json_infer(value: &serde_json::Value) -> Result<Cvss, ParseError> {
+        let vector = value
+            .get("vectorString")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| ParseError::InvalidJson {
+                message: "missing field `vectorString`".to_string(),
+            })?;
+        let (version, _) = utils::prefix::extract_version_from_optional_prefix(vector)?;
+
+        let invalid_json = |e: serde_json::Error| ParseError::InvalidJson {
+            message: e.to_string(),
+        };
+        let v3 = |version| {
+            serde_json::from_value::<v3::CvssV3>(value.clone())
+                .map(|c| v3::CvssV3 {
+                    version: Some(version),
+                    ..c
+                })
+                .map_err(invalid_json)
+        };
+        match version {
+            None | Some(Version::V2) => serde_json::from_value(value.clone())
+                .map(Cvss::V2)
+                .map_err(invalid_json),
+            Some(Version::V3_0) => v3(version::VersionV3::V3_0).map(Cvss::V3_0),
+            Some(Version::V3_1) => v3(version::VersionV3::V3_1).map(Cvss::V3_1),
+            Some(Version::V4) => serde_json::from_value(value.clone())
+                .map(Cvss::V4)
+                .map_err(invalid_json),
+        }
+    }
+
+    /// Parses a vector string of any supported CVSS version like [`Cvss::from_str`], but without
+    /// requiring the base metrics.
+    ///
+    /// See `from_str_lenient` on [`v2_0::CvssV2`], [`v3::CvssV3`] and [`v4_0::CvssV4`].
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseError> {
+        let (version, _) = utils::prefix::extract_version_from_optional_prefix(s)?;
+
+        match version {
+            None | Some(Version::V2) => v2_0::CvssV2::from_str_lenient(s).map(Cvss::V2),
+            Some(Version::V3_0) => v3::CvssV3::from_str_lenient(s).map(Cvss::V3_0),
+            Some(Version::V3_1) => v3::CvssV3::from_str_lenient(s).map(Cvss::V3_1),
+            Some(Version::V4) => v4_0::CvssV4::from_str_lenient(s).map(Cvss::V4),
+        }
+    }
+
+    /// Parses the vector string in the fragment of a FIRST calculator URL, e.g.
+    /// `https://www.first.org/cvss/calculator/3.1#CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+    ///
+    /// The URL shapes of the CVSS v3.x and v4.0 calculators are supported. The fragment is
+    /// parsed with [`Cvss::from_str`].
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::InvalidPrefixLabel`] if the URL has no fragment starting with `CVSS:`
+    /// * Any error of [`Cvss::from_str`] for an invalid vector in the fragment
+    pub fn from_calculator_url(url: &str) -> Result<Self, ParseError> {
+        let fragment = url
+            .split_once('#')
+            .map(|(_, fragment)| fragment.trim())
+            .filter(|fragment| fragment.starts_with("CVSS:"))
+            .ok_or_else(|| ParseError::InvalidPrefixLabel {
+                found: url.to_string(),
+            })?;
+        Cvss::from_str(fragment)
+    }
+
+    /// Parses a vector string like [`Cvss::from_str`], but also accepts it as the value of an
+    /// environment-variable-style assignment, e.g. `CVSS=CVSS:3.1/AV:N/AC:L/...`, as passed by
+    /// some CI tools.
+    ///
+    /// The `CVSS=` name is matched case-insensitively and surrounding whitespace is ignored.
+    /// Vectors without the assignment are parsed unchanged.
+    pub fn from_assignment(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        let vector = s
+            .get(..5)
+            .filter(|name| name.eq_ignore_ascii_case("CVSS="))
+            .map_or(s, |_| &s[5..]);
+        Cvss::from_str(vector)
+    }
+
     /// Returns the version of the CVSS standard.
     pub fn version(&self) -> Version {
         self.into()
@@ -98,6 +451,93 @@ impl Cvss {
         }
     }
 
+    /// Consumes the object and returns its vector string without cloning it.
+    pub fn into_vector_string(self) -> String {
+        match self {
+            Cvss::V2(c) => c.into_vector_string(),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => c.into_vector_string(),
+            Cvss::V4(c) => c.into_vector_string(),
+        }
+    }
+
+    /// Returns the metrics that are set, as `(abbreviation, value)` pairs in specification
+    /// order, e.g. `("AV", "N")`.
+    pub fn metrics(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Cvss::V2(c) => c.metrics(),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => c.metrics(),
+            Cvss::V4(c) => c.metrics(),
+        }
+    }
+
+    /// Builds the vector string from the metric fields, reflecting any changes to them.
+    ///
+    /// See [`CvssV3::to_vector_string`](v3::CvssV3::to_vector_string); CVSS v2.0 vectors are
+    /// built without the optional `CVSS:2.0/` prefix.
+    pub fn to_vector_string(&self) -> String {
+        match self {
+            Cvss::V2(c) => c.to_vector_string(),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => c.to_vector_string(),
+            Cvss::V4(c) => c.to_vector_string(),
+        }
+    }
+
+    /// Builds the vector string from the metric fields like [`Cvss::to_vector_string`], but
+    /// keeps Not Defined (`X`, or `ND` for CVSS v2.0) metrics.
+    pub fn to_vector_string_verbose(&self) -> String {
+        match self {
+            Cvss::V2(c) => c.to_vector_string_verbose(),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => c.to_vector_string_verbose(),
+            Cvss::V4(c) => c.to_vector_string_verbose(),
+        }
+    }
+
+    /// Builds the canonical form of the vector string, in specification order and without Not
+    /// Defined metrics, e.g. for deduplication or for comparison against other tools.
+    ///
+    /// See [`CvssV3::normalized_vector`](v3::CvssV3::normalized_vector).
+    pub fn normalized_vector(&self) -> String {
+        match self {
+            Cvss::V2(c) => c.normalized_vector(),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => c.normalized_vector(),
+            Cvss::V4(c) => c.normalized_vector(),
+        }
+    }
+
+    /// Returns whether both objects have the same base metrics, treating CVSS v3.0 and v3.1 as
+    /// the same version.
+    ///
+    /// Unlike `==`, which compares the exact version and all metrics, this is meant to
+    /// deduplicate findings across a version bump, e.g. a v3.0 and a v3.1 vector with the same
+    /// base metrics. Objects of different major versions, like v2.0 and v3.1, never have the same
+    /// base.
+    pub fn same_base(&self, other: &Cvss) -> bool {
+        let major = |cvss: &Cvss| match cvss {
+            Cvss::V2(_) => 2,
+            Cvss::V3_0(_) | Cvss::V3_1(_) => 3,
+            Cvss::V4(_) => 4,
+        };
+        let base_metrics = |cvss: &Cvss| {
+            let version = cvss.version();
+            cvss.metrics()
+                .into_iter()
+                .filter(|(metric, _)| version.metric_group(metric) == Some(MetricGroup::Base))
+                .collect::<Vec<_>>()
+        };
+
+        major(self) == major(other) && base_metrics(self) == base_metrics(other)
+    }
+
+    /// Returns the metrics of the vector string without the `CVSS:X.Y/` prefix.
+    ///
+    /// Vectors without a prefix (which is optional for CVSS v2.0) are returned unchanged.
+    pub fn metrics_string(&self) -> String {
+        let vector = self.vector_string();
+        utils::prefix::extract_version_from_optional_prefix(vector)
+            .map_or(vector, |(_, metrics)| metrics)
+            .to_string()
+    }
+
     /// Returns the base score.
     pub fn base_score(&self) -> f64 {
         match self {
@@ -108,6 +548,28 @@ impl Cvss {
         }
     }
 
+    /// Calculates the base score from the base metrics.
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_base_score(&self) -> Option<f64> {
+        match self {
+            Cvss::V2(c) => c.calculated_base_score(),
+            Cvss::V3_0(c) => c.calculated_base_score(),
+            Cvss::V3_1(c) => c.calculated_base_score(),
+            Cvss::V4(c) => c.calculated_base_score(),
+        }
+    }
+
+    /// Returns whether all base metrics are present, so that the base score can be calculated.
+    ///
+    /// Only vectors parsed leniently, e.g. with [`Cvss::from_str_lenient`], can be incomplete.
+    pub fn is_complete(&self) -> bool {
+        match self {
+            Cvss::V2(c) => c.is_complete(),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => c.is_complete(),
+            Cvss::V4(c) => c.is_complete(),
+        }
+    }
+
     /// Returns the base severity.
     pub fn base_severity(&self) -> Option<Severity> {
         match self {
@@ -117,11 +579,254 @@ impl Cvss {
             Cvss::V4(c) => c.base_severity(),
         }
     }
+
+    /// Returns the qualitative severity rating of [`Cvss::calculated_base_score`], rather than
+    /// the stored severity of [`Cvss::base_severity`].
+    /// Returns None if required base metrics are missing.
+    ///
+    /// This gives the same severity however the object was created, e.g. also for objects
+    /// parsed from vector strings, which have no stored severity. CVSS v2.0 scores are rated
+    /// with the NVD bands, which have no Critical rating.
+    pub fn computed_severity(&self) -> Option<Severity> {
+        let score = self.calculated_base_score()?;
+        let severity = match self {
+            Cvss::V2(_) => Severity::from_score(score).min(Severity::High),
+            Cvss::V3_0(_) | Cvss::V3_1(_) | Cvss::V4(_) => Severity::from_score(score),
+        };
+        Some(severity)
+    }
+
+    /// Returns a key for sorting by base score, where equal scores are ordered by the recency of
+    /// the CVSS version (v2.0 < v3.0 < v3.1 < v4.0).
+    ///
+    /// Like for the [`Ord`] implementation, the calculated base score is used, falling back to
+    /// the stored base score if the vector is incomplete. Sorting ascending by this key places
+    /// the most severe, most recently assessed entry last.
+    pub fn sort_key(&self) -> (OrderedScore, u8) {
+        let score = self
+            .calculated_base_score()
+            .unwrap_or_else(|| self.base_score());
+        (OrderedScore(score), self.version_rank())
+    }
+
+    fn version_rank(&self) -> u8 {
+        match self.version() {
+            Version::V2 => 0,
+            Version::V3_0 => 1,
+            Version::V3_1 => 2,
+            Version::V4 => 3,
+        }
+    }
+
+    /// Compares the severity of two CVSS objects by their calculated base scores, regardless of
+    /// their versions.
+    ///
+    /// If the base score of an object cannot be calculated, its stored base score is used
+    /// instead. Equal scores compare as equal, even if the versions differ; use
+    /// [`Cvss::sort_key`] to order those by version as well.
+    pub fn severity_cmp(&self, other: &Cvss) -> Ordering {
+        let score = |c: &Cvss| c.calculated_base_score().unwrap_or_else(|| c.base_score());
+        score(self).total_cmp(&score(other))
+    }
+
+    /// Returns how completely the vector is assessed, between 0.0 and 1.0.
+    ///
+    /// This is the weighted fraction of the metrics of each group that are set (see
+    /// [`Cvss::metrics`]): base metrics weigh 0.6, temporal (threat in CVSS v4.0) metrics 0.2 and
+    /// environmental metrics 0.2. Metrics set to Not Defined (`X` or `ND`) do not count as
+    /// present, and supplemental metrics are ignored. A vector with all base metrics but no
+    /// others has a confidence of 0.6.
+    pub fn confidence(&self) -> f64 {
+        let version = self.version();
+        let defined: Vec<&'static str> = self
+            .metrics()
+            .into_iter()
+            .filter(|(_, value)| value != "X" && value != "ND")
+            .map(|(key, _)| key)
+            .collect();
+        let fraction = |group: MetricGroup| {
+            let (present, total) = version.metrics_in_group(group).fold((0, 0), |(p, t), m| {
+                (p + usize::from(defined.contains(&m)), t + 1)
+            });
+            present as f64 / total as f64
+        };
+        let threat = match version {
+            Version::V4 => MetricGroup::Threat,
+            _ => MetricGroup::Temporal,
+        };
+
+        0.6 * fraction(MetricGroup::Base)
+            + 0.2 * fraction(threat)
+            + 0.2 * fraction(MetricGroup::Environmental)
+    }
+
+    /// Returns the base score formatted for the SARIF `security-severity` property, e.g. `"9.8"`.
+    ///
+    /// The score is calculated from the metrics (see [`Cvss::calculated_base_score`]), so that
+    /// objects parsed from vector strings are ranked correctly, and the stored base score is used
+    /// for incomplete vectors. The score always has one decimal place, so a score of zero is
+    /// `"0.0"` rather than `"0"`.
+    pub fn sarif_security_severity(&self) -> String {
+        let score = self
+            .calculated_base_score()
+            .unwrap_or_else(|| self.base_score());
+        format!("{score:.1}")
+    }
+
+    /// Returns an entry of the `severity` array of an [OSV](https://ossf.github.io/osv-schema/)
+    /// record, e.g. `{"type": "CVSS_V3", "score": "CVSS:3.1/AV:N/..."}`.
+    ///
+    /// The score is the canonical vector string of the metrics, with metrics in specification
+    /// order, rather than the stored vector string.
+    #[cfg(feature = "serde")]
+    pub fn to_osv_severity(&self) -> serde_json::Value {
+        let (osv_type, score) = match self {
+            Cvss::V2(c) => ("CVSS_V2", c.to_string()),
+            Cvss::V3_0(c) | Cvss::V3_1(c) => ("CVSS_V3", c.to_string()),
+            Cvss::V4(c) => ("CVSS_V4", c.to_string()),
+        };
+        serde_json::json!({ "type": osv_type, "score": score })
+    }
+
+    /// Returns the JSON representation without the metrics that are Not Defined.
+    ///
+    /// Metrics set to Not Defined (`X`, or `ND` in CVSS v2.0) do not affect the score, so they
+    /// are dropped from both the metric fields and the vector string, which is built from the
+    /// metric fields (see [`Cvss::to_vector_string`]). This considerably shrinks e.g. CVSS v4.0
+    /// objects with many undefined environmental metrics. The required fields, like the version,
+    /// vector string and base score, are always included.
+    #[cfg(feature = "serde")]
+    pub fn to_minimal_json(&self) -> serde_json::Value {
+        let mut value = serde_json::to_value(self).expect("CVSS objects serialize to JSON");
+        if let Some(object) = value.as_object_mut() {
+            object.retain(|_, v| !matches!(v.as_str(), Some("NOT_DEFINED" | "NOTDEFINED")));
+
+            object.insert("vectorString".to_string(), self.to_vector_string().into());
+        }
+        value
+    }
+
+    /// Returns up to `n` base metrics that contribute most to the score, most severe first.
+    ///
+    /// Each metric is paired with its contribution between 0.0 and 1.0, where 1.0 means the metric
+    /// takes its most severe value. For CVSS v2.0 and v3.x this is the metric weight relative to
+    /// the worst-case weight; for CVSS v4.0 it is the severity distance from the least severe
+    /// value. Ties keep specification order.
+    pub fn top_contributors(&self, n: usize) -> Vec<(&'static str, f64)> {
+        let mut contributions = match self {
+            Cvss::V2(c) => c.base_contributions(),
+            Cvss::V3_0(c) => c.base_contributions(),
+            Cvss::V3_1(c) => c.base_contributions(),
+            Cvss::V4(c) => c.base_contributions(),
+        };
+        contributions.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        contributions.truncate(n);
+        contributions
+    }
+
+    /// Returns a [shields.io](https://shields.io) badge URL showing `label` and the base score.
+    ///
+    /// The score and severity are calculated from the metrics, falling back to the stored values
+    /// for incomplete vectors (see [`Cvss::sarif_security_severity`] and
+    /// [`Cvss::computed_severity`]). The badge is colored by [`Severity::color_hex`] of the
+    /// severity, or grey if the severity is unknown.
+    pub fn badge_url(&self, label: &str) -> String {
+        let color = self
+            .computed_severity()
+            .or_else(|| self.base_severity())
+            .map_or("lightgrey", |severity| &severity.color_hex()[1..]);
+        format!(
+            "https://img.shields.io/badge/{}-{}-{}",
+            escape_badge_text(label),
+            escape_badge_text(&self.sarif_security_severity()),
+            color
+        )
+    }
+
+    /// Encodes the metrics as a fixed-size numeric feature vector.
+    ///
+    /// The length and column order depend on the version; see `FEATURE_COLUMNS` and
+    /// `feature_vector` on [`v2_0::CvssV2`], [`v3::CvssV3`] and [`v4_0::CvssV4`].
+    pub fn feature_vector(&self) -> Vec<f64> {
+        match self {
+            Cvss::V2(c) => c.feature_vector(),
+            Cvss::V3_0(c) => c.feature_vector(),
+            Cvss::V3_1(c) => c.feature_vector(),
+            Cvss::V4(c) => c.feature_vector(),
+        }
+    }
+}
+
+/// Returns the change in base score from `a` to `b`, i.e. `b.base_score() - a.base_score()`.
+///
+/// This is typically used to compare the scores of one vulnerability across CVSS versions,
+/// e.g. a positive delta from a v2.0 score to a v3.1 score means the newer version rates the
+/// vulnerability as more severe.
+pub fn version_delta(a: &Cvss, b: &Cvss) -> f64 {
+    b.base_score() - a.base_score()
+}
+
+/// Groups the CVSS objects of one vulnerability by version, oldest version first.
+///
+/// Only versions that have at least one object are returned, and objects of the same version
+/// keep their input order. The first entry of the first and last group can be passed to
+/// [`version_delta`] to compare the oldest and newest assessment.
+pub fn group_by_version(scores: &[Cvss]) -> Vec<(Version, Vec<&Cvss>)> {
+    [Version::V2, Version::V3_0, Version::V3_1, Version::V4]
+        .into_iter()
+        .map(|version| {
+            let group: Vec<&Cvss> = scores.iter().filter(|c| c.version() == version).collect();
+            (version, group)
+        })
+        .filter(|(_, group)| !group.is_empty())
+        .collect()
+}
+
+/// Returns the CVSS objects whose base severity is at least `min`, keeping their order.
+///
+/// The severity is computed from the metrics (see [`Cvss::computed_severity`]), so objects
+/// parsed from vector strings are rated too. The stored severity of [`Cvss::base_severity`] is
+/// used for incomplete vectors, and objects without either severity are never returned. Note
+/// that CVSS v2.0 severities are at most High.
+pub fn filter_min_severity(scores: &[Cvss], min: Severity) -> Vec<&Cvss> {
+    scores
+        .iter()
+        .filter(|c| {
+            c.computed_severity()
+                .or_else(|| c.base_severity())
+                .is_some_and(|severity| severity >= min)
+        })
+        .collect()
+}
+
+/// Parses a list of vector strings separated by newlines or commas.
+///
+/// Each entry is trimmed and parsed with [`Cvss::from_str`], so the version is detected from its
+/// prefix. Empty entries are skipped, and the results keep the order of the input.
+pub fn parse_list(s: &str) -> Vec<Result<Cvss, ParseError>> {
+    s.split(['\n', ','])
+        .map(str::trim)
+        .filter(|vector| !vector.is_empty())
+        .map(Cvss::from_str)
+        .collect()
 }
 
 /// Represents the qualitative severity rating of a vulnerability.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
-#[serde(rename_all = "UPPERCASE")]
+///
+/// Severities are parsed case-insensitively from, and displayed as, the uppercase spellings of
+/// the specifications, e.g. `CRITICAL`.
+///
+/// ```
+/// use cvss_rs::Severity;
+///
+/// assert_eq!("critical".parse::<Severity>(), Ok(Severity::Critical));
+/// assert_eq!(Severity::Medium.to_string(), "MEDIUM");
+/// assert!("SEVERE".parse::<Severity>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Display, EnumString)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[strum(serialize_all = "UPPERCASE", ascii_case_insensitive)]
 pub enum Severity {
     None,
     Low,
@@ -129,3 +834,90 @@ pub enum Severity {
     High,
     Critical,
 }
+
+impl Severity {
+    /// Returns the qualitative severity rating for a score.
+    ///
+    /// This is the rating scale of CVSS v3.x and v4.0: 0.0 is None, 0.1-3.9 Low, 4.0-6.9 Medium,
+    /// 7.0-8.9 High and 9.0-10.0 Critical. CVSS v2.0 has no official rating scale.
+    ///
+    /// NaN is not a valid score and is rated None, rather than falling through to Critical.
+    pub fn from_score(score: f64) -> Self {
+        if score.is_nan() || score <= 0.0 {
+            Severity::None
+        } else if score < 4.0 {
+            Severity::Low
+        } else if score < 7.0 {
+            Severity::Medium
+        } else if score < 9.0 {
+            Severity::High
+        } else {
+            Severity::Critical
+        }
+    }
+
+    /// Returns the color of the severity as a `#rrggbb` hex string.
+    ///
+    /// The colors are those of the FIRST CVSS calculators, from green for None to red for
+    /// Critical.
+    pub fn color_hex(&self) -> &'static str {
+        match self {
+            Severity::None => "#53aa33",
+            Severity::Low => "#ffcb0d",
+            Severity::Medium => "#f9a009",
+            Severity::High => "#df3d03",
+            Severity::Critical => "#cc0500",
+        }
+    }
+}
+
+/// Escapes text for a path segment of a shields.io static badge URL.
+///
+/// Dashes and underscores are doubled and spaces become underscores, as required by shields.io.
+/// Other characters that are not URL-safe are percent-encoded.
+fn escape_badge_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '-' => escaped.push_str("--"),
+            '_' => escaped.push_str("__"),
+            ' ' => escaped.push('_'),
+            c if c.is_ascii_alphanumeric() || c == '.' || c == '~' => escaped.push(c),
+            c => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    escaped.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+/// A score with a total order, for use as a sort key.
+///
+/// Scores are compared with [`f64::total_cmp`], so the order is total even for NaN: a positive
+/// NaN sorts above all other scores and a negative NaN below them. Likewise, -0.0 sorts below
+/// 0.0 and is not equal to it.
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedScore(pub f64);
+
+impl PartialEq for OrderedScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedScore {}
+
+impl PartialOrd for OrderedScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
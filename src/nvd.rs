@@ -0,0 +1,51 @@
+//! Reading CVSS data from the NVD API.
+//!
+//! The NVD CVE API nests the CVSS objects of a vulnerability in per-version arrays, e.g.
+//! `{"cvssMetricV31": [{"source": "nvd@nist.gov", "cvssData": {...}}]}`.
+
+use alloc::vec::Vec;
+use serde_json::Value;
+
+use crate::Cvss;
+
+/// The keys of the NVD metric arrays, newest version first.
+const METRIC_KEYS: [&str; 4] = [
+    "cvssMetricV40",
+    "cvssMetricV31",
+    "cvssMetricV30",
+    "cvssMetricV2",
+];
+
+/// Returns all CVSS objects in the `metrics` object of an NVD vulnerability.
+///
+/// `value` may be the `metrics` object itself or the `cve` object containing it. The
+/// `cvssData` of each entry of the `cvssMetricV40`, `cvssMetricV31`, `cvssMetricV30` and
+/// `cvssMetricV2` arrays is parsed, in that order. For CVSS v2.0, NVD stores the severity next
+/// to `cvssData` as `baseSeverity`, which is used if `cvssData` has no severity of its own.
+/// Likewise, the `exploitabilityScore` and `impactScore` subscores next to `cvssData` are
+/// captured. Entries that cannot be parsed are skipped.
+pub fn from_nvd_metrics(value: &Value) -> Vec<Cvss> {
+    let metrics = value.get("metrics").unwrap_or(value);
+
+    METRIC_KEYS
+        .iter()
+        .filter_map(|key| metrics.get(key)?.as_array())
+        .flatten()
+        .filter_map(|entry| {
+            let mut data = entry.get("cvssData")?.clone();
+            if let Some(object) = data.as_object_mut() {
+                for key in ["exploitabilityScore", "impactScore"] {
+                    if let Some(score) = entry.get(key) {
+                        object.entry(key).or_insert_with(|| score.clone());
+                    }
+                }
+                if let Some(severity) = entry.get("baseSeverity") {
+                    if object.get("version").and_then(Value::as_str) == Some("2.0") {
+                        object.entry("severity").or_insert_with(|| severity.clone());
+                    }
+                }
+            }
+            serde_json::from_value(data).ok()
+        })
+        .collect()
+}
@@ -0,0 +1,57 @@
+//! Prioritizing vulnerabilities by severity and likelihood of exploitation.
+//!
+//! A [`Tier`] combines a CVSS base score with an [EPSS](https://www.first.org/epss/) score, the
+//! estimated probability (0.0 to 1.0) that a vulnerability is exploited in the next 30 days.
+
+/// A prioritization bucket, ordered from least to most urgent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tier {
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// Returns the prioritization tier of a vulnerability from its CVSS base score and EPSS score.
+///
+/// The tier is taken from this decision table, where a missing EPSS score is treated like a
+/// moderate one:
+///
+/// | CVSS score | EPSS ≥ 0.5 | 0.1 ≤ EPSS < 0.5 or unknown | EPSS < 0.1 |
+/// |------------|------------|-----------------------------|------------|
+/// | 9.0 - 10.0 | Critical   | High                        | High       |
+/// | 7.0 - 8.9  | Critical   | High                        | Medium     |
+/// | 4.0 - 6.9  | High       | Medium                      | Low        |
+/// | 0.1 - 3.9  | Medium     | Low                         | Low        |
+/// | 0.0        | Info       | Info                        | Info       |
+///
+/// # Example
+///
+/// ```
+/// use cvss_rs::prioritize::{tier, Tier};
+///
+/// assert_eq!(tier(9.8, Some(0.97)), Tier::Critical);
+/// assert_eq!(tier(9.8, Some(0.01)), Tier::High);
+/// assert_eq!(tier(5.3, None), Tier::Medium);
+/// ```
+pub fn tier(cvss_score: f64, epss: Option<f64>) -> Tier {
+    // 0 for EPSS < 0.1, 1 for a moderate or unknown EPSS, 2 for EPSS >= 0.5
+    let likelihood = match epss {
+        Some(epss) if epss >= 0.5 => 2,
+        Some(epss) if epss < 0.1 => 0,
+        _ => 1,
+    };
+
+    match (cvss_score, likelihood) {
+        (s, _) if s <= 0.0 => Tier::Info,
+        (s, 2) if s >= 7.0 => Tier::Critical,
+        (s, _) if s >= 9.0 => Tier::High,
+        (s, 1) if s >= 7.0 => Tier::High,
+        (s, 2) if s >= 4.0 => Tier::High,
+        (s, _) if s >= 7.0 => Tier::Medium,
+        (s, 1) if s >= 4.0 => Tier::Medium,
+        (_, 2) => Tier::Medium,
+        _ => Tier::Low,
+    }
+}
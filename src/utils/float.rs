@@ -0,0 +1,63 @@
+//! Floating point functions that `core` does not provide.
+//!
+//! With the `std` feature these are the `f64` methods of the standard library, so scores are
+//! computed exactly as before. Without it, they are implemented for the small, positive values
+//! that appear in CVSS formulas.
+
+/// Rounds half away from zero, like [`f64::round`].
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+/// Rounds half away from zero, like [`f64::round`].
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f64) -> f64 {
+    let truncated = trunc(x);
+    let fraction = x - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Returns the smallest integer greater than or equal to `x`, like [`f64::ceil`].
+#[cfg(feature = "std")]
+pub(crate) fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+/// Returns the smallest integer greater than or equal to `x`, like [`f64::ceil`].
+#[cfg(not(feature = "std"))]
+pub(crate) fn ceil(x: f64) -> f64 {
+    let truncated = trunc(x);
+    if truncated < x {
+        truncated + 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Raises `x` to the power `n`, like [`f64::powf`].
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    x.powf(n)
+}
+
+/// Raises `x` to the power `n`, like [`f64::powf`].
+///
+/// Only non-negative integer exponents are supported, which is all the CVSS formulas use.
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    debug_assert!(n >= 0.0 && trunc(n) == n);
+    (0..n as u32).fold(1.0, |acc, _| acc * x)
+}
+
+/// Drops the fractional part of `x`. Exact for every `|x| < 2^63`.
+#[cfg(not(feature = "std"))]
+fn trunc(x: f64) -> f64 {
+    x as i64 as f64
+}
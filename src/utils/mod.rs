@@ -1,4 +1,7 @@
 //! Utility modules for CVSS parsing and validation.
 
+pub(crate) mod float;
 pub(crate) mod parse_metrics;
 pub(crate) mod prefix;
+#[cfg(feature = "serde")]
+pub(crate) mod score_serde;
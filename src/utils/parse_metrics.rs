@@ -1,5 +1,8 @@
 use crate::ParseError;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
 
 /// Generic helper function for parsing and setting metrics. It checks for duplicate metrics
 /// and invalid metric values.
@@ -23,12 +26,123 @@ pub(crate) fn parse_metric<T: FromStr>(
     if field.is_some() {
         return Err(ParseError::DuplicateMetric {
             metric: key.to_string(),
+            position: None,
         });
     }
     // check metric value validity -> either set value or throw invalid value error
     *field = Some(value.parse().map_err(|_| ParseError::InvalidMetricValue {
         metric: key.to_string(),
         value: value.to_string(),
+        position: None,
     })?);
     Ok(())
 }
+
+/// Splits a `KEY:VALUE` vector component into its key and value, both converted to uppercase.
+///
+/// # Returns
+///
+/// * `Ok((key, value))` if the component consists of exactly one key and one value
+/// * `Err(ParseError::InvalidComponent)` otherwise
+pub(crate) fn split_component(component: &str) -> Result<(String, String), ParseError> {
+    let invalid = || ParseError::InvalidComponent {
+        component: component.to_string(),
+        position: None,
+    };
+
+    let mut parts = component.split(':');
+    let key = parts.next().ok_or_else(invalid)?.to_ascii_uppercase();
+    let value = parts.next().ok_or_else(invalid)?.to_ascii_uppercase();
+
+    // Check for extra colons
+    if parts.next().is_some() {
+        return Err(invalid());
+    }
+
+    Ok((key, value))
+}
+
+/// Formats a metric that is set as a `(abbreviation, value)` pair, e.g. `("AV", "N")`, the
+/// inverse of [`split_component`].
+pub(crate) fn metric_pair(
+    key: &'static str,
+    value: &Option<impl fmt::Display>,
+) -> Option<(&'static str, String)> {
+    value.as_ref().map(|value| (key, value.to_string()))
+}
+
+/// The keys of the pseudo-metrics that some tools append to vector strings to carry the stored
+/// scores, e.g. `CVSS:3.1/AV:N/.../baseScore:9.8/baseSeverity:CRITICAL`.
+const SCORE_PSEUDO_METRICS: [&str; 3] = ["BASESCORE", "BASESEVERITY", "TEMPORALSCORE"];
+
+/// The stored scores carried by the score pseudo-metrics of a vector string.
+///
+/// The values are collected while parsing and applied afterwards, so that the result does not
+/// depend on the order of the pseudo-metrics.
+pub(crate) struct ScorePseudoMetrics<S> {
+    pub(crate) base_score: Option<f64>,
+    pub(crate) base_severity: Option<S>,
+    pub(crate) temporal_score: Option<f64>,
+}
+
+impl<S: FromStr> ScorePseudoMetrics<S> {
+    pub(crate) fn new() -> Self {
+        ScorePseudoMetrics {
+            base_score: None,
+            base_severity: None,
+            temporal_score: None,
+        }
+    }
+
+    /// Parses `value` if `key` is a score pseudo-metric. Both are expected in uppercase.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` if the pseudo-metric was successfully parsed and set
+    /// * `Ok(false)` if `key` is not a score pseudo-metric
+    /// * `Err(ParseError)` if the pseudo-metric is a duplicate or if parsing fails
+    pub(crate) fn parse(&mut self, key: &str, value: &str) -> Result<bool, ParseError> {
+        match key {
+            "BASESCORE" => parse_score(&mut self.base_score, value, key)?,
+            "BASESEVERITY" => parse_metric(&mut self.base_severity, value, key)?,
+            "TEMPORALSCORE" => parse_score(&mut self.temporal_score, value, key)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// Like [`parse_metric`], but for scores, which must be between 0.0 and 10.0.
+fn parse_score(field: &mut Option<f64>, value: &str, key: &str) -> Result<(), ParseError> {
+    if field.is_some() {
+        return Err(ParseError::DuplicateMetric {
+            metric: key.to_string(),
+            position: None,
+        });
+    }
+    let score = value
+        .parse()
+        .ok()
+        .filter(|score| (0.0..=10.0).contains(score))
+        .ok_or_else(|| ParseError::InvalidMetricValue {
+            metric: key.to_string(),
+            value: value.to_string(),
+            position: None,
+        })?;
+    *field = Some(score);
+    Ok(())
+}
+
+/// Removes the score pseudo-metrics from a vector string, as they are not part of the vector.
+pub(crate) fn without_score_pseudo_metrics(vector: &str) -> String {
+    vector
+        .split('/')
+        .filter(|component| {
+            let key = component.split(':').next().unwrap_or_default();
+            !SCORE_PSEUDO_METRICS
+                .iter()
+                .any(|pseudo| pseudo.eq_ignore_ascii_case(key))
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
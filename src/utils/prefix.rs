@@ -1,7 +1,8 @@
 //! Utilities for validating and parsing CVSS vector prefixes.
 
 use crate::{ParseError, Version};
-use std::str::FromStr;
+use alloc::string::ToString;
+use core::str::FromStr;
 
 /// Validates and parses a CVSS vector prefix into a [`Version`].
 ///
@@ -0,0 +1,40 @@
+use alloc::string::String;
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer};
+
+/// A score as found in JSON, either as a number or as a string like `"9.8"`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawScore {
+    Number(f64),
+    String(String),
+}
+
+impl RawScore {
+    fn into_score<E: Error>(self) -> Result<f64, E> {
+        match self {
+            RawScore::Number(score) => Ok(score),
+            RawScore::String(s) => s
+                .trim()
+                .parse()
+                .map_err(|_| E::invalid_value(Unexpected::Str(&s), &"a numeric score")),
+        }
+    }
+}
+
+/// Deserializes a score given either as a number or as a string-encoded number.
+pub(crate) fn deserialize_score<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<f64, D::Error> {
+    RawScore::deserialize(deserializer)?.into_score()
+}
+
+/// Like [`deserialize_score`], for optional scores. Fields using this also need
+/// `#[serde(default)]`, so that they may be absent.
+pub(crate) fn deserialize_optional_score<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<f64>, D::Error> {
+    Option::<RawScore>::deserialize(deserializer)?
+        .map(RawScore::into_score)
+        .transpose()
+}
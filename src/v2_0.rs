@@ -1,87 +1,156 @@
 //! Represents the CVSS v2.0 specification.
 
-use std::fmt;
-use std::str::FromStr;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-use crate::utils::{parse_metrics::parse_metric, prefix};
+use crate::utils::float;
+use crate::utils::parse_metrics::{
+    metric_pair, parse_metric, split_component, without_score_pseudo_metrics, ScorePseudoMetrics,
+};
+use crate::utils::prefix;
+#[cfg(feature = "serde")]
+use crate::utils::score_serde::{deserialize_optional_score, deserialize_score};
+use crate::v3::CvssV3;
+use crate::version::MetricGroup;
 use crate::{ParseError, Severity as UnifiedSeverity, Version};
 
 /// Represents a CVSS v2.0 score object.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CvssV2 {
     /// The CVSS vector string.
     pub vector_string: String,
     /// The qualitative severity rating.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub severity: Option<Severity>,
     /// The base score, a value between 0.0 and 10.0.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_score"))]
     pub base_score: f64,
     /// The temporal score, a value between 0.0 and 10.0.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub temporal_score: Option<f64>,
     /// The environmental score, a value between 0.0 and 10.0.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub environmental_score: Option<f64>,
     /// The access vector metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub access_vector: Option<AccessVector>,
     /// The access complexity metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub access_complexity: Option<AccessComplexity>,
     /// The authentication metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub authentication: Option<Authentication>,
     /// The confidentiality impact metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub confidentiality_impact: Option<Impact>,
     /// The integrity impact metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub integrity_impact: Option<Impact>,
     /// The availability impact metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub availability_impact: Option<Impact>,
+    /// The exploitability subscore, as included by NVD next to the base score.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub exploitability_score: Option<f64>,
+    /// The impact subscore, as included by NVD next to the base score.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub impact_score: Option<f64>,
     /// The exploitability metric (temporal).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub exploitability: Option<Exploitability>,
     /// The remediation level metric (temporal).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub remediation_level: Option<RemediationLevel>,
     /// The report confidence metric (temporal).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub report_confidence: Option<ReportConfidence>,
     /// The collateral damage potential metric (environmental).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub collateral_damage_potential: Option<CollateralDamagePotential>,
     /// The target distribution metric (environmental).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub target_distribution: Option<TargetDistribution>,
     /// The confidentiality requirement metric (environmental).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub confidentiality_requirement: Option<SecurityRequirement>,
     /// The integrity requirement metric (environmental).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub integrity_requirement: Option<SecurityRequirement>,
     /// The availability requirement metric (environmental).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub availability_requirement: Option<SecurityRequirement>,
 }
 
 /// Represents the qualitative severity rating of a vulnerability.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+///
+/// CVSS v2.0 defines no rating scale. The bands used by NVD are authoritative in practice:
+/// 0.1-3.9 is Low, 4.0-6.9 Medium and 7.0-10.0 High. There is no Critical rating. A score of 0.0
+/// is rated None, see [`Severity::from_score`].
+#[derive(Clone, Debug, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
+#[strum(serialize_all = "UPPERCASE")]
 pub enum Severity {
+    /// Not part of the CVSS v2.0 rating scale, but emitted by NVD for records scored 0.0.
+    #[cfg_attr(feature = "serde", serde(alias = "NONE"))]
+    None,
+    #[cfg_attr(feature = "serde", serde(alias = "LOW"))]
     Low,
+    #[cfg_attr(feature = "serde", serde(alias = "MEDIUM"))]
     Medium,
+    #[cfg_attr(feature = "serde", serde(alias = "HIGH"))]
     High,
 }
 
+impl Severity {
+    /// Returns the qualitative severity rating for a score per the NVD CVSS v2.0 bands.
+    ///
+    /// A score of 0.0 is rated None, like NVD does for such records, instead of Low. NaN is not a
+    /// valid score and is rated None as well.
+    pub fn from_score(score: f64) -> Self {
+        if score.is_nan() || score <= 0.0 {
+            Severity::None
+        } else if score < 4.0 {
+            Severity::Low
+        } else if score < 7.0 {
+            Severity::Medium
+        } else {
+            Severity::High
+        }
+    }
+}
+
 /// Represents the access vector metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum AccessVector {
     #[strum(serialize = "N")]
     Network,
@@ -103,8 +172,9 @@ impl AccessVector {
 }
 
 /// Represents the access complexity metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum AccessComplexity {
     #[strum(serialize = "H")]
     High,
@@ -126,8 +196,9 @@ impl AccessComplexity {
 }
 
 /// Represents the authentication metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum Authentication {
     #[strum(serialize = "M")]
     Multiple,
@@ -149,8 +220,9 @@ impl Authentication {
 }
 
 /// Represents the impact metrics (confidentiality, integrity, availability).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum Impact {
     #[strum(serialize = "N")]
     None,
@@ -172,8 +244,9 @@ impl Impact {
 }
 
 /// Exploitability (E) - Temporal metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Exploitability {
     #[strum(serialize = "U")]
     Unproven,
@@ -201,8 +274,9 @@ impl Exploitability {
 }
 
 /// Remediation Level (RL) - Temporal metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum RemediationLevel {
     #[strum(serialize = "OF")]
     OfficialFix,
@@ -230,8 +304,9 @@ impl RemediationLevel {
 }
 
 /// Report Confidence (RC) - Temporal metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum ReportConfidence {
     #[strum(serialize = "UC")]
     Unconfirmed,
@@ -256,8 +331,9 @@ impl ReportConfidence {
 }
 
 /// Collateral Damage Potential (CDP) - Environmental metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum CollateralDamagePotential {
     #[strum(serialize = "N")]
     None,
@@ -288,8 +364,9 @@ impl CollateralDamagePotential {
 }
 
 /// Target Distribution (TD) - Environmental metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum TargetDistribution {
     #[strum(serialize = "N")]
     None,
@@ -317,8 +394,9 @@ impl TargetDistribution {
 }
 
 /// Security Requirement (CR, IR, AR) - Environmental metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum SecurityRequirement {
     #[strum(serialize = "L")]
     Low,
@@ -342,8 +420,13 @@ impl SecurityRequirement {
     }
 }
 
+/// Rounds to one decimal place, with halves rounded away from zero, as the CVSS v2.0 guide's
+/// `round_to_1_decimal` does, e.g. 7.459 becomes 7.5.
+///
+/// Unlike the `Roundup` of CVSS v3, scores are rounded to the nearest value, not up, so 7.44
+/// becomes 7.4.
 fn round_to_first_decimal(value: f64) -> f64 {
-    (value * 10.0).round() / 10.0
+    float::round(value * 10.0) / 10.0
 }
 
 enum ImpactKind {
@@ -351,21 +434,469 @@ enum ImpactKind {
     WithAdjustedImpact,
 }
 
+/// Selects how [`CvssV2::to_v3_approx`] maps Access Complexity Medium (`AC:M`), which has no
+/// CVSS v3 equivalent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComplexityMapping {
+    /// Maps `AC:M` to `AC:L`, treating the vulnerability as easy to exploit.
+    MediumToLow,
+    /// Maps `AC:M` to `AC:H`, treating the vulnerability as hard to exploit.
+    MediumToHigh,
+}
+
+/// The subscores and scores of a CVSS v2.0 vector, as returned by
+/// [`CvssV2::score_breakdown`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBreakdown {
+    /// The impact subscore, see [`CvssV2::impact_subscore`].
+    pub impact: f64,
+    /// The exploitability subscore, see [`CvssV2::exploitability_subscore`].
+    pub exploitability: f64,
+    /// The base score.
+    pub base: f64,
+    /// The temporal score, if any temporal metrics are present.
+    pub temporal: Option<f64>,
+    /// The environmental score, if any environmental metrics are present.
+    pub environmental: Option<f64>,
+    /// The abbreviations of the metrics that contributed to the scores, i.e. that are present
+    /// and not Not Defined, in specification order.
+    pub metrics: Vec<&'static str>,
+}
+
 impl CvssV2 {
     pub fn vector_string(&self) -> &str {
         &self.vector_string
     }
 
+    /// Consumes the object and returns its vector string without cloning it.
+    pub fn into_vector_string(self) -> String {
+        self.vector_string
+    }
+
+    /// Returns the metrics that are set, as `(abbreviation, value)` pairs in specification
+    /// order, e.g. `("AV", "N")`.
+    pub fn metrics(&self) -> Vec<(&'static str, String)> {
+        [
+            metric_pair("AV", &self.access_vector),
+            metric_pair("AC", &self.access_complexity),
+            metric_pair("Au", &self.authentication),
+            metric_pair("C", &self.confidentiality_impact),
+            metric_pair("I", &self.integrity_impact),
+            metric_pair("A", &self.availability_impact),
+            metric_pair("E", &self.exploitability),
+            metric_pair("RL", &self.remediation_level),
+            metric_pair("RC", &self.report_confidence),
+            metric_pair("CDP", &self.collateral_damage_potential),
+            metric_pair("TD", &self.target_distribution),
+            metric_pair("CR", &self.confidentiality_requirement),
+            metric_pair("IR", &self.integrity_requirement),
+            metric_pair("AR", &self.availability_requirement),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Builds the vector string from the metric fields, without the `CVSS:2.0/` prefix.
+    ///
+    /// Unlike the stored [`CvssV2::vector_string`], this reflects changes to the fields. Only
+    /// present metrics are written, in specification order, e.g. `AV:N/AC:L/Au:N/C:C/I:C/A:C`.
+    /// Not Defined (`ND`) metrics are omitted; use [`CvssV2::to_vector_string_verbose`] to keep
+    /// them.
+    pub fn to_vector_string(&self) -> String {
+        self.format_vector(true)
+    }
+
+    /// Builds the vector string from the metric fields like [`CvssV2::to_vector_string`], but
+    /// keeps Not Defined (`ND`) metrics.
+    pub fn to_vector_string_verbose(&self) -> String {
+        self.format_vector(false)
+    }
+
+    /// Builds the canonical form of the vector string, e.g. for deduplication or for comparison
+    /// against other tools.
+    ///
+    /// This is the same as [`CvssV2::to_vector_string`]: metrics are written in specification
+    /// order with canonical casing and without prefix, and Not Defined (`ND`) metrics are
+    /// omitted.
+    pub fn normalized_vector(&self) -> String {
+        self.to_vector_string()
+    }
+
+    fn format_vector(&self, skip_not_defined: bool) -> String {
+        self.metrics()
+            .into_iter()
+            .filter(|(_, value)| !(skip_not_defined && value == "ND"))
+            .map(|(key, value)| format!("{key}:{value}"))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Formats the base metrics the way NVD spells CVSS v2.0 vector strings, e.g.
+    /// `AV:N/AC:L/Au:N/C:P/I:P/A:P`.
+    ///
+    /// NVD omits the `CVSS:2.0/` prefix and the temporal and environmental metrics, and spells
+    /// the Authentication metric `Au`. For consumers expecting all-caps keys such as `AU`, use
+    /// `to_string_nvd().to_ascii_uppercase()`, as all values are uppercase already.
+    pub fn to_string_nvd(&self) -> String {
+        self.metrics()
+            .into_iter()
+            .filter(|(key, _)| Version::V2.metric_group(key) == Some(MetricGroup::Base))
+            .map(|(key, value)| format!("{key}:{value}"))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
     pub fn base_score(&self) -> f64 {
         self.base_score
     }
 
+    /// Returns the stored severity as a unified severity.
+    ///
+    /// If no severity is stored, a vector whose calculated base score is 0.0 has severity None,
+    /// like in the other versions. Otherwise, the severity is unknown.
     pub fn base_severity(&self) -> Option<UnifiedSeverity> {
-        self.severity.as_ref().map(|s| match s {
-            Severity::Low => UnifiedSeverity::Low,
-            Severity::Medium => UnifiedSeverity::Medium,
-            Severity::High => UnifiedSeverity::High,
-        })
+        match &self.severity {
+            Some(Severity::None) => Some(UnifiedSeverity::None),
+            Some(Severity::Low) => Some(UnifiedSeverity::Low),
+            Some(Severity::Medium) => Some(UnifiedSeverity::Medium),
+            Some(Severity::High) => Some(UnifiedSeverity::High),
+            None => (self.calculated_base_score() == Some(0.0)).then_some(UnifiedSeverity::None),
+        }
+    }
+
+    /// Returns the weight of each present base metric relative to its worst-case weight, in
+    /// specification order.
+    ///
+    /// A value of 1.0 means the metric takes its most severe value.
+    pub(crate) fn base_contributions(&self) -> Vec<(&'static str, f64)> {
+        let impact_max = Impact::Complete.score();
+
+        [
+            (
+                "AV",
+                self.access_vector
+                    .as_ref()
+                    .map(|av| av.score() / AccessVector::Network.score()),
+            ),
+            (
+                "AC",
+                self.access_complexity
+                    .as_ref()
+                    .map(|ac| ac.score() / AccessComplexity::Low.score()),
+            ),
+            (
+                "Au",
+                self.authentication
+                    .as_ref()
+                    .map(|au| au.score() / Authentication::None.score()),
+            ),
+            (
+                "C",
+                self.confidentiality_impact
+                    .as_ref()
+                    .map(|c| c.score() / impact_max),
+            ),
+            (
+                "I",
+                self.integrity_impact
+                    .as_ref()
+                    .map(|i| i.score() / impact_max),
+            ),
+            (
+                "A",
+                self.availability_impact
+                    .as_ref()
+                    .map(|a| a.score() / impact_max),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(metric, contribution)| Some((metric, contribution?)))
+        .collect()
+    }
+
+    /// Converts this vector to a CVSS v3.1 vector with a freshly computed vector string and
+    /// scores, as a best-effort heuristic for migrating old advisories.
+    ///
+    /// This is [`CvssV2::to_v3_approx`] with `AC:M` mapped to `AC:L`. The conversion is lossy:
+    /// the v2.0 score is not carried over, and User Interaction and Scope are assumed to be
+    /// `UI:N` and `S:U`, so the v3 score is not an authoritative re-assessment.
+    ///
+    /// Returns None if required base metrics are missing.
+    pub fn to_v3(&self) -> Option<CvssV3> {
+        self.to_v3_approx(ComplexityMapping::MediumToLow)
+    }
+
+    /// Approximates this vector as a CVSS v3.1 vector, with computed scores.
+    ///
+    /// The conversion is lossy, as the versions do not share all metrics and values:
+    ///
+    /// * `AC:M` maps to `AC:L` or `AC:H`, as selected by `complexity`
+    /// * Authentication maps to Privileges Required (`Au:N` → `PR:N`, `Au:S` → `PR:L`,
+    ///   `Au:M` → `PR:H`)
+    /// * User Interaction and Scope do not exist in CVSS v2.0 and are set to `UI:N` and `S:U`
+    /// * Partial and Complete impacts map to Low and High
+    /// * Temporal metrics map to their CVSS v3 equivalents, while environmental metrics are
+    ///   dropped, as Collateral Damage Potential and Target Distribution have no equivalent
+    ///
+    /// Returns None if required base metrics are missing.
+    pub fn to_v3_approx(&self, complexity: ComplexityMapping) -> Option<CvssV3> {
+        let mut metrics = Vec::new();
+        let mut insert = |key: &'static str, value: &'static str| metrics.push((key, value));
+        let impact = |impact: &Impact| match impact {
+            Impact::None => "N",
+            Impact::Partial => "L",
+            Impact::Complete => "H",
+        };
+
+        insert(
+            "AV",
+            match self.access_vector.as_ref()? {
+                AccessVector::Network => "N",
+                AccessVector::AdjacentNetwork => "A",
+                AccessVector::Local => "L",
+            },
+        );
+        insert(
+            "AC",
+            match (self.access_complexity.as_ref()?, complexity) {
+                (AccessComplexity::Low, _)
+                | (AccessComplexity::Medium, ComplexityMapping::MediumToLow) => "L",
+                (AccessComplexity::High, _)
+                | (AccessComplexity::Medium, ComplexityMapping::MediumToHigh) => "H",
+            },
+        );
+        insert(
+            "PR",
+            match self.authentication.as_ref()? {
+                Authentication::None => "N",
+                Authentication::Single => "L",
+                Authentication::Multiple => "H",
+            },
+        );
+        insert("UI", "N");
+        insert("S", "U");
+        insert("C", impact(self.confidentiality_impact.as_ref()?));
+        insert("I", impact(self.integrity_impact.as_ref()?));
+        insert("A", impact(self.availability_impact.as_ref()?));
+
+        if let Some(e) = &self.exploitability {
+            insert(
+                "E",
+                match e {
+                    Exploitability::Unproven => "U",
+                    Exploitability::ProofOfConcept => "P",
+                    Exploitability::Functional => "F",
+                    Exploitability::High => "H",
+                    Exploitability::NotDefined => "X",
+                },
+            );
+        }
+        if let Some(rl) = &self.remediation_level {
+            insert(
+                "RL",
+                match rl {
+                    RemediationLevel::OfficialFix => "O",
+                    RemediationLevel::TemporaryFix => "T",
+                    RemediationLevel::Workaround => "W",
+                    RemediationLevel::Unavailable => "U",
+                    RemediationLevel::NotDefined => "X",
+                },
+            );
+        }
+        if let Some(rc) = &self.report_confidence {
+            insert(
+                "RC",
+                match rc {
+                    ReportConfidence::Unconfirmed => "U",
+                    ReportConfidence::Uncorroborated => "R",
+                    ReportConfidence::Confirmed => "C",
+                    ReportConfidence::NotDefined => "X",
+                },
+            );
+        }
+
+        CvssV3::from_metric_pairs(Version::V3_1, metrics).ok()
+    }
+
+    /// Parses a CVSS v2.0 vector string like [`CvssV2::from_str`], but without requiring the
+    /// base metrics, e.g. `AV:N/AC:L`.
+    ///
+    /// Partial vectors cannot be scored, so the `calculated_*` methods return None for them.
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseError> {
+        // try to extract version prefix and extract components
+        let (version_opt, components_str) = prefix::extract_version_from_optional_prefix(s)?;
+
+        // if a prefix exists, its version must be 2.0
+        if let Some(version) = version_opt {
+            prefix::validate_allowed_prefix_version(&version, &[Version::V2])?;
+        }
+
+        let mut cvss = CvssV2::empty(without_score_pseudo_metrics(s));
+
+        // Parse metrics, tracking the byte offset of each component for error reporting
+        let mut scores = ScorePseudoMetrics::new();
+        let mut offset = s.len() - components_str.len();
+        for component in components_str.split('/') {
+            let position = offset;
+            offset += component.len() + 1;
+            if component.is_empty() {
+                continue;
+            }
+
+            split_component(component)
+                .and_then(|(key, value)| cvss.set_component(&mut scores, &key, &value))
+                .map_err(|err| err.at(position))?;
+        }
+        cvss.set_scores(scores);
+
+        Ok(cvss)
+    }
+
+    /// Returns whether all six base metrics are present, so that the base score can be
+    /// calculated.
+    pub fn is_complete(&self) -> bool {
+        self.missing_base_metric().is_none()
+    }
+
+    /// Returns the abbreviation of the first missing base metric, in specification order.
+    fn missing_base_metric(&self) -> Option<&'static str> {
+        [
+            ("AV", self.access_vector.is_none()),
+            ("AC", self.access_complexity.is_none()),
+            ("Au", self.authentication.is_none()),
+            ("C", self.confidentiality_impact.is_none()),
+            ("I", self.integrity_impact.is_none()),
+            ("A", self.availability_impact.is_none()),
+        ]
+        .into_iter()
+        .find_map(|(metric, missing)| missing.then_some(metric))
+    }
+
+    /// Creates a `CvssV2` without any metrics or scores.
+    pub(crate) fn empty(vector_string: String) -> Self {
+        CvssV2 {
+            vector_string,
+            severity: None,
+            base_score: 0.0,
+            temporal_score: None,
+            environmental_score: None,
+            access_vector: None,
+            access_complexity: None,
+            authentication: None,
+            confidentiality_impact: None,
+            integrity_impact: None,
+            availability_impact: None,
+            exploitability_score: None,
+            impact_score: None,
+            exploitability: None,
+            remediation_level: None,
+            report_confidence: None,
+            collateral_damage_potential: None,
+            target_distribution: None,
+            confidentiality_requirement: None,
+            integrity_requirement: None,
+            availability_requirement: None,
+        }
+    }
+
+    /// Like [`CvssV2::set_metric`], but also accepts the `BASESCORE`, `BASESEVERITY` and
+    /// `TEMPORALSCORE` pseudo-metrics, which are collected in `scores`.
+    pub(crate) fn set_component(
+        &mut self,
+        scores: &mut ScorePseudoMetrics<Severity>,
+        key: &str,
+        value: &str,
+    ) -> Result<(), ParseError> {
+        if !scores.parse(key, value)? {
+            self.set_metric(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Populates the stored score fields from the score pseudo-metrics. Without an explicit
+    /// `baseSeverity`, the severity is derived from `baseScore`.
+    pub(crate) fn set_scores(&mut self, scores: ScorePseudoMetrics<Severity>) {
+        if let Some(score) = scores.base_score {
+            self.base_score = score;
+            self.severity = Some(Severity::from_score(score));
+        }
+        if scores.base_severity.is_some() {
+            self.severity = scores.base_severity;
+        }
+        if scores.temporal_score.is_some() {
+            self.temporal_score = scores.temporal_score;
+        }
+    }
+
+    /// Parses `value` and stores it in the metric field identified by the abbreviation `key`.
+    /// Both are expected in uppercase.
+    pub(crate) fn set_metric(&mut self, key: &str, value: &str) -> Result<(), ParseError> {
+        match key {
+            "AV" => parse_metric(&mut self.access_vector, value, key)?,
+            "AC" => parse_metric(&mut self.access_complexity, value, key)?,
+            "AU" => parse_metric(&mut self.authentication, value, key)?,
+            "C" => parse_metric(&mut self.confidentiality_impact, value, key)?,
+            "I" => parse_metric(&mut self.integrity_impact, value, key)?,
+            "A" => parse_metric(&mut self.availability_impact, value, key)?,
+            // Temporal metrics
+            "E" => parse_metric(&mut self.exploitability, value, key)?,
+            "RL" => parse_metric(&mut self.remediation_level, value, key)?,
+            "RC" => parse_metric(&mut self.report_confidence, value, key)?,
+            // Environmental metrics
+            "CDP" => parse_metric(&mut self.collateral_damage_potential, value, key)?,
+            "TD" => parse_metric(&mut self.target_distribution, value, key)?,
+            "CR" => parse_metric(&mut self.confidentiality_requirement, value, key)?,
+            "IR" => parse_metric(&mut self.integrity_requirement, value, key)?,
+            "AR" => parse_metric(&mut self.availability_requirement, value, key)?,
+            _ => {
+                return Err(ParseError::UnknownMetric {
+                    metric: key.to_string(),
+                    position: None,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Column names of [`CvssV2::feature_vector`], in order.
+    pub const FEATURE_COLUMNS: [&'static str; 14] = [
+        "AV", "AC", "Au", "C", "I", "A", "E", "RL", "RC", "CDP", "TD", "CR", "IR", "AR",
+    ];
+
+    /// Encodes the metrics as a fixed-size numeric feature vector, e.g. as input for a model.
+    ///
+    /// Each column holds the metric's weight from the specification (its `score()`), in the
+    /// order given by [`CvssV2::FEATURE_COLUMNS`]. Absent metrics are encoded as `-1.0`, which no
+    /// weight can take, so that they differ from present metrics with a weight of `0.0`, like
+    /// `C:N`.
+    pub fn feature_vector(&self) -> Vec<f64> {
+        fn weight<T>(metric: &Option<T>, score: impl Fn(&T) -> f64) -> f64 {
+            metric.as_ref().map_or(-1.0, score)
+        }
+
+        vec![
+            weight(&self.access_vector, AccessVector::score),
+            weight(&self.access_complexity, AccessComplexity::score),
+            weight(&self.authentication, Authentication::score),
+            weight(&self.confidentiality_impact, Impact::score),
+            weight(&self.integrity_impact, Impact::score),
+            weight(&self.availability_impact, Impact::score),
+            weight(&self.exploitability, Exploitability::score),
+            weight(&self.remediation_level, RemediationLevel::score),
+            weight(&self.report_confidence, ReportConfidence::score),
+            weight(
+                &self.collateral_damage_potential,
+                CollateralDamagePotential::score,
+            ),
+            weight(&self.target_distribution, TargetDistribution::score),
+            weight(
+                &self.confidentiality_requirement,
+                SecurityRequirement::score,
+            ),
+            weight(&self.integrity_requirement, SecurityRequirement::score),
+            weight(&self.availability_requirement, SecurityRequirement::score),
+        ]
     }
 
     /// Calculates the base score from the base metrics.
@@ -390,6 +921,103 @@ impl CvssV2 {
         self.calculate_base_score(ImpactKind::WithImpact)
     }
 
+    /// Calculates the base score like [`CvssV2::calculated_base_score`], but before it is
+    /// rounded to one decimal place, i.e. `((0.6 × Impact) + (0.4 × Exploitability) − 1.5) ×
+    /// f(Impact)`. Returns None if required base metrics are missing.
+    ///
+    /// CVSS v2.0 rounds this value to the nearest decimal, unlike the `Roundup` of CVSS v3.
+    pub fn unrounded_base_score(&self) -> Option<f64> {
+        self.unrounded_score(ImpactKind::WithImpact)
+    }
+
+    /// Returns the qualitative severity rating of `calculated_base_score()`.
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_base_severity(&self) -> Option<Severity> {
+        self.calculated_base_score().map(Severity::from_score)
+    }
+
+    /// Calculates the exploitability subscore, `20 × AV × AC × Au`, from the base metrics.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// The subscore is not rounded.
+    pub fn exploitability_subscore(&self) -> Option<f64> {
+        let av = self.access_vector.as_ref()?;
+        let ac = self.access_complexity.as_ref()?;
+        let au = self.authentication.as_ref()?;
+
+        Some(20.0 * av.score() * ac.score() * au.score())
+    }
+
+    /// Calculates the impact subscore, `10.41 × (1 − (1 − C) × (1 − I) × (1 − A))`, from the base
+    /// metrics. Returns None if required base metrics are missing.
+    ///
+    /// The subscore is not rounded.
+    pub fn impact_subscore(&self) -> Option<f64> {
+        let ci = self.confidentiality_impact.as_ref()?;
+        let ii = self.integrity_impact.as_ref()?;
+        let ai = self.availability_impact.as_ref()?;
+
+        Some(10.41 * (1.0 - (1.0 - ci.score()) * (1.0 - ii.score()) * (1.0 - ai.score())))
+    }
+
+    /// Returns the subscores and scores of the vector, with the metrics that contributed to
+    /// them. Returns None if required base metrics are missing.
+    ///
+    /// The temporal and environmental scores are only included if such metrics are present,
+    /// like in the scores populated by [`CvssV2Builder::build`].
+    pub fn score_breakdown(&self) -> Option<ScoreBreakdown> {
+        Some(ScoreBreakdown {
+            impact: self.impact_subscore()?,
+            exploitability: self.exploitability_subscore()?,
+            base: self.calculated_base_score()?,
+            temporal: self
+                .has_temporal_metrics()
+                .then(|| self.calculated_temporal_score())
+                .flatten(),
+            environmental: self
+                .has_environmental_metrics()
+                .then(|| self.calculated_environmental_score())
+                .flatten(),
+            metrics: self
+                .metrics()
+                .into_iter()
+                .filter(|(_, value)| value != "ND")
+                .map(|(metric, _)| metric)
+                .collect(),
+        })
+    }
+
+    /// Returns whether any temporal metric is present, even if Not Defined.
+    fn has_temporal_metrics(&self) -> bool {
+        self.exploitability.is_some()
+            || self.remediation_level.is_some()
+            || self.report_confidence.is_some()
+    }
+
+    /// Returns whether any environmental metric is present, even if Not Defined.
+    fn has_environmental_metrics(&self) -> bool {
+        self.collateral_damage_potential.is_some()
+            || self.target_distribution.is_some()
+            || self.confidentiality_requirement.is_some()
+            || self.integrity_requirement.is_some()
+            || self.availability_requirement.is_some()
+    }
+
+    /// Returns whether the stored `exploitability_score` and `impact_score` match the calculated
+    /// subscores, rounded to one decimal place like NVD does.
+    ///
+    /// Absent stored subscores are not checked. Returns false if a subscore is stored, but cannot
+    /// be calculated.
+    pub fn subscores_consistent(&self) -> bool {
+        let matches = |stored: Option<f64>, calculated: Option<f64>| {
+            stored.is_none_or(|stored| {
+                calculated.is_some_and(|c| round_to_first_decimal(c) == stored)
+            })
+        };
+        matches(self.exploitability_score, self.exploitability_subscore())
+            && matches(self.impact_score, self.impact_subscore())
+    }
+
     /// Calculates the base score from the base metrics and an enum to select which impact score to use.
     ///
     /// Required base metrics are:
@@ -414,19 +1042,20 @@ impl CvssV2 {
     /// - `Some(base_score)` if the required base metrics are present.
     /// - `None` if any of the required base metrics are missing.
     fn calculate_base_score(&self, impact_kind: ImpactKind) -> Option<f64> {
+        // Round to 1 decimal place
+        self.unrounded_score(impact_kind)
+            .map(round_to_first_decimal)
+    }
+
+    fn unrounded_score(&self, impact_kind: ImpactKind) -> Option<f64> {
         // All base metrics are required
         let ci = self.confidentiality_impact.as_ref()?;
         let ii = self.integrity_impact.as_ref()?;
         let ai = self.availability_impact.as_ref()?;
-        let av = self.access_vector.as_ref()?;
-        let ac = self.access_complexity.as_ref()?;
-        let au = self.authentication.as_ref()?;
 
         // Calculate impact specified in `impact` argument.
         let impact_score = match impact_kind {
-            ImpactKind::WithImpact => {
-                10.41 * (1.0 - (1.0 - ci.score()) * (1.0 - ii.score()) * (1.0 - ai.score()))
-            }
+            ImpactKind::WithImpact => self.impact_subscore()?,
             ImpactKind::WithAdjustedImpact => {
                 // requirement scores default to 1.0 (not defined) if not specified
                 let cr = self
@@ -452,16 +1081,14 @@ impl CvssV2 {
         };
 
         // Calculate exploitability
-        let exploitability = 20.0 * av.score() * ac.score() * au.score();
+        let exploitability = self.exploitability_subscore()?;
 
         // f(impact) = 0 if impact = 0, else 1.176
         let f_impact = if impact_score == 0.0 { 0.0 } else { 1.176 };
 
         // Calculate base score
         let score = ((0.6 * impact_score) + (0.4 * exploitability) - 1.5) * f_impact;
-
-        // Round to 1 decimal place
-        Some(round_to_first_decimal(score))
+        Some(score)
     }
 
     /// Calculates the temporal score from the base metrics and temporal metrics.
@@ -553,112 +1180,210 @@ impl CvssV2 {
 impl FromStr for CvssV2 {
     type Err = ParseError;
 
+    /// Parses a CVSS v2.0 vector string, with or without the optional `CVSS:2.0/` prefix.
+    ///
+    /// The scores are not calculated, but the `baseScore`, `baseSeverity` and `temporalScore`
+    /// pseudo-metrics are accepted like for [`CvssV3::from_str`].
+    ///
+    /// All base metrics are required; see [`CvssV2::from_str_lenient`] for partial vectors.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // try to extract version prefix and extract components
-        let (version_opt, components_str) = prefix::extract_version_from_optional_prefix(s)?;
-
-        // if a prefix exists, its version must be 2.0
-        if let Some(version) = version_opt {
-            prefix::validate_allowed_prefix_version(&version, &[Version::V2])?;
+        let cvss = CvssV2::from_str_lenient(s)?;
+        match cvss.missing_base_metric() {
+            Some(metric) => Err(ParseError::MissingRequiredMetric {
+                metric: metric.to_string(),
+            }),
+            None => Ok(cvss),
         }
+    }
+}
 
-        let mut cvss = CvssV2 {
-            vector_string: s.to_string(),
-            severity: None,
-            base_score: 0.0,
-            temporal_score: None,
-            environmental_score: None,
-            access_vector: None,
-            access_complexity: None,
-            authentication: None,
-            confidentiality_impact: None,
-            integrity_impact: None,
-            availability_impact: None,
-            exploitability: None,
-            remediation_level: None,
-            report_confidence: None,
-            collateral_damage_potential: None,
-            target_distribution: None,
-            confidentiality_requirement: None,
-            integrity_requirement: None,
-            availability_requirement: None,
-        };
-
-        // Parse metrics
-        for component in components_str.split('/') {
-            if component.is_empty() {
-                continue;
-            }
+impl fmt::Display for CvssV2 {
+    /// Formats the metrics as a vector string without prefix, see [`CvssV2::to_vector_string`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_vector_string())
+    }
+}
 
-            let mut parts = component.split(':');
-            let key = parts
-                .next()
-                .ok_or_else(|| ParseError::InvalidComponent {
-                    component: component.to_string(),
-                })?
-                .to_ascii_uppercase();
-            let value = parts
-                .next()
-                .ok_or_else(|| ParseError::InvalidComponent {
-                    component: component.to_string(),
-                })?
-                .to_ascii_uppercase();
-
-            // Check for extra colons
-            if parts.next().is_some() {
-                return Err(ParseError::InvalidComponent {
-                    component: component.to_string(),
-                });
-            }
+/// Objects are equal if they have the same metrics, compared by their normalized vector strings
+/// (see [`CvssV2::normalized_vector`]), even if their original vector strings list the metrics in
+/// a different order or write out Not Defined metrics.
+///
+/// The floating-point score fields are intentionally excluded from the comparison and the hash.
+impl PartialEq for CvssV2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized_vector() == other.normalized_vector()
+    }
+}
 
-            match key.as_str() {
-                "AV" => parse_metric(&mut cvss.access_vector, &value, &key)?,
-                "AC" => parse_metric(&mut cvss.access_complexity, &value, &key)?,
-                "AU" => parse_metric(&mut cvss.authentication, &value, &key)?,
-                "C" => parse_metric(&mut cvss.confidentiality_impact, &value, &key)?,
-                "I" => parse_metric(&mut cvss.integrity_impact, &value, &key)?,
-                "A" => parse_metric(&mut cvss.availability_impact, &value, &key)?,
-                // Temporal metrics
-                "E" => parse_metric(&mut cvss.exploitability, &value, &key)?,
-                "RL" => parse_metric(&mut cvss.remediation_level, &value, &key)?,
-                "RC" => parse_metric(&mut cvss.report_confidence, &value, &key)?,
-                // Environmental metrics
-                "CDP" => parse_metric(&mut cvss.collateral_damage_potential, &value, &key)?,
-                "TD" => parse_metric(&mut cvss.target_distribution, &value, &key)?,
-                "CR" => parse_metric(&mut cvss.confidentiality_requirement, &value, &key)?,
-                "IR" => parse_metric(&mut cvss.integrity_requirement, &value, &key)?,
-                "AR" => parse_metric(&mut cvss.availability_requirement, &value, &key)?,
-                _ => return Err(ParseError::UnknownMetric { metric: key }),
-            }
-        }
+impl Eq for CvssV2 {}
 
-        Ok(cvss)
+impl Hash for CvssV2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized_vector().hash(state);
     }
 }
 
-impl fmt::Display for CvssV2 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // CVSS v2 typically doesn't include version prefix, but we'll include it for consistency
-        write!(f, "AV:")?;
-        if let Some(av) = &self.access_vector {
-            write!(f, "{}", av)?;
-        }
-        if let Some(ac) = &self.access_complexity {
-            write!(f, "/AC:{}", ac)?;
-        }
-        if let Some(au) = &self.authentication {
-            write!(f, "/Au:{}", au)?;
+/// Builds a [`CvssV2`] from its metrics, see [`CvssV2::builder`].
+#[derive(Clone, Debug)]
+pub struct CvssV2Builder {
+    cvss: CvssV2,
+}
+
+impl CvssV2 {
+    /// Returns a builder for constructing a `CvssV2` from its metrics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cvss_rs::v2_0::{AccessComplexity, AccessVector, Authentication, CvssV2, Impact};
+    ///
+    /// let cvss = CvssV2::builder()
+    ///     .access_vector(AccessVector::Network)
+    ///     .access_complexity(AccessComplexity::Low)
+    ///     .authentication(Authentication::None)
+    ///     .confidentiality_impact(Impact::Partial)
+    ///     .integrity_impact(Impact::Partial)
+    ///     .availability_impact(Impact::Partial)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cvss.vector_string, "AV:N/AC:L/Au:N/C:P/I:P/A:P");
+    /// assert_eq!(cvss.base_score, 7.5);
+    /// ```
+    pub fn builder() -> CvssV2Builder {
+        CvssV2Builder {
+            cvss: CvssV2::empty(String::new()),
         }
-        if let Some(c) = &self.confidentiality_impact {
-            write!(f, "/C:{}", c)?;
+    }
+}
+
+impl CvssV2Builder {
+    /// Sets the access vector metric (AV).
+    pub fn access_vector(mut self, value: AccessVector) -> Self {
+        self.cvss.access_vector = Some(value);
+        self
+    }
+
+    /// Sets the access complexity metric (AC).
+    pub fn access_complexity(mut self, value: AccessComplexity) -> Self {
+        self.cvss.access_complexity = Some(value);
+        self
+    }
+
+    /// Sets the authentication metric (Au).
+    pub fn authentication(mut self, value: Authentication) -> Self {
+        self.cvss.authentication = Some(value);
+        self
+    }
+
+    /// Sets the confidentiality impact metric (C).
+    pub fn confidentiality_impact(mut self, value: Impact) -> Self {
+        self.cvss.confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the integrity impact metric (I).
+    pub fn integrity_impact(mut self, value: Impact) -> Self {
+        self.cvss.integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the availability impact metric (A).
+    pub fn availability_impact(mut self, value: Impact) -> Self {
+        self.cvss.availability_impact = Some(value);
+        self
+    }
+
+    /// Sets the exploitability metric (E).
+    pub fn exploitability(mut self, value: Exploitability) -> Self {
+        self.cvss.exploitability = Some(value);
+        self
+    }
+
+    /// Sets the remediation level metric (RL).
+    pub fn remediation_level(mut self, value: RemediationLevel) -> Self {
+        self.cvss.remediation_level = Some(value);
+        self
+    }
+
+    /// Sets the report confidence metric (RC).
+    pub fn report_confidence(mut self, value: ReportConfidence) -> Self {
+        self.cvss.report_confidence = Some(value);
+        self
+    }
+
+    /// Sets the collateral damage potential metric (CDP).
+    pub fn collateral_damage_potential(mut self, value: CollateralDamagePotential) -> Self {
+        self.cvss.collateral_damage_potential = Some(value);
+        self
+    }
+
+    /// Sets the target distribution metric (TD).
+    pub fn target_distribution(mut self, value: TargetDistribution) -> Self {
+        self.cvss.target_distribution = Some(value);
+        self
+    }
+
+    /// Sets the confidentiality requirement metric (CR).
+    pub fn confidentiality_requirement(mut self, value: SecurityRequirement) -> Self {
+        self.cvss.confidentiality_requirement = Some(value);
+        self
+    }
+
+    /// Sets the integrity requirement metric (IR).
+    pub fn integrity_requirement(mut self, value: SecurityRequirement) -> Self {
+        self.cvss.integrity_requirement = Some(value);
+        self
+    }
+
+    /// Sets the availability requirement metric (AR).
+    pub fn availability_requirement(mut self, value: SecurityRequirement) -> Self {
+        self.cvss.availability_requirement = Some(value);
+        self
+    }
+
+    /// Builds the `CvssV2`.
+    ///
+    /// The vector string, the base score and severity, and the temporal and environmental
+    /// scores (if any such metrics are set) are computed from the metrics.
+    ///
+    /// # Errors
+    ///
+    /// [`ParseError::MissingRequiredMetric`] naming the first missing base metric.
+    pub fn build(self) -> Result<CvssV2, ParseError> {
+        let mut cvss = self.cvss;
+        if let Some(metric) = cvss.missing_base_metric() {
+            return Err(ParseError::MissingRequiredMetric {
+                metric: metric.to_string(),
+            });
         }
-        if let Some(i) = &self.integrity_impact {
-            write!(f, "/I:{}", i)?;
+
+        cvss.vector_string = cvss.to_string();
+        cvss.base_score = cvss.calculated_base_score().unwrap_or_default();
+        cvss.severity = cvss.calculated_base_severity();
+
+        if cvss.has_temporal_metrics() {
+            cvss.temporal_score = cvss.calculated_temporal_score();
         }
-        if let Some(a) = &self.availability_impact {
-            write!(f, "/A:{}", a)?;
+
+        if cvss.has_environmental_metrics() {
+            cvss.environmental_score = cvss.calculated_environmental_score();
         }
 
-        Ok(())
+        Ok(cvss)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_first_decimal() {
+        // Examples of the CVSS v2.0 guide
+        assert_eq!(round_to_first_decimal(7.459), 7.5);
+        assert_eq!(round_to_first_decimal(7.44), 7.4);
+        assert_eq!(round_to_first_decimal(10.000_845), 10.0);
+        assert_eq!(round_to_first_decimal(0.0), 0.0);
     }
 }
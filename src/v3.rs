@@ -1,96 +1,141 @@
 //! Represents the CVSS v3.0 and v3.1 specifications.
 
-use std::fmt;
-use std::str::FromStr;
-
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-use crate::utils::{parse_metrics::parse_metric, prefix};
-use crate::{version::VersionV3, ParseError, Severity as UnifiedSeverity, Version};
+use crate::utils::float;
+use crate::utils::parse_metrics::{
+    metric_pair, parse_metric, split_component, without_score_pseudo_metrics, ScorePseudoMetrics,
+};
+use crate::utils::prefix;
+#[cfg(feature = "serde")]
+use crate::utils::score_serde::{deserialize_optional_score, deserialize_score};
+use crate::{version::VersionV3, OrderedScore, ParseError, Severity as UnifiedSeverity, Version};
 
 /// Represents a CVSS v3.0 or v3.1 score object.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CvssV3 {
     /// The CVSS vector string.
+    ///
+    /// Scores are calculated from the metric fields, not from this string, so it may be empty,
+    /// e.g. for JSON objects that only contain the metrics.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub vector_string: String,
     /// The specific CVSS v3 version (3.0 or 3.1).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub version: Option<VersionV3>,
     /// The base score, a value between 0.0 and 10.0.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_score"))]
     pub base_score: f64,
     /// The qualitative severity rating for the base score.
     pub base_severity: Severity,
     /// The attack vector metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub attack_vector: Option<AttackVector>,
     /// The attack complexity metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub attack_complexity: Option<AttackComplexity>,
     /// The privileges required metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub privileges_required: Option<PrivilegesRequired>,
     /// The user interaction metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub user_interaction: Option<UserInteraction>,
     /// The scope metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub scope: Option<Scope>,
     /// The confidentiality impact metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub confidentiality_impact: Option<Impact>,
     /// The integrity impact metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub integrity_impact: Option<Impact>,
     /// The availability impact metric.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub availability_impact: Option<Impact>,
+    /// The exploitability subscore, as included by NVD next to the base score.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub exploitability_score: Option<f64>,
+    /// The impact subscore, as included by NVD next to the base score.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub impact_score: Option<f64>,
 
     // Temporal Metrics
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub temporal_score: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub temporal_severity: Option<Severity>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub exploit_code_maturity: Option<ExploitCodeMaturity>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub remediation_level: Option<RemediationLevel>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub report_confidence: Option<ReportConfidence>,
 
     // Environmental Metrics
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "deserialize_optional_score")
+    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub environmental_score: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub environmental_severity: Option<Severity>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub confidentiality_requirement: Option<SecurityRequirement>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub integrity_requirement: Option<SecurityRequirement>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub availability_requirement: Option<SecurityRequirement>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_attack_vector: Option<AttackVector>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_attack_complexity: Option<AttackComplexity>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_privileges_required: Option<PrivilegesRequired>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_user_interaction: Option<UserInteraction>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_scope: Option<Scope>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_confidentiality_impact: Option<Impact>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_integrity_impact: Option<Impact>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_availability_impact: Option<Impact>,
 }
 
 /// Represents the qualitative severity rating of a vulnerability.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[strum(serialize_all = "UPPERCASE")]
 pub enum Severity {
     None,
     Low,
@@ -99,9 +144,23 @@ pub enum Severity {
     Critical,
 }
 
+impl Severity {
+    /// Returns the qualitative severity rating for a score per the CVSS v3.x rating scale.
+    pub fn from_score(score: f64) -> Self {
+        match UnifiedSeverity::from_score(score) {
+            UnifiedSeverity::None => Severity::None,
+            UnifiedSeverity::Low => Severity::Low,
+            UnifiedSeverity::Medium => Severity::Medium,
+            UnifiedSeverity::High => Severity::High,
+            UnifiedSeverity::Critical => Severity::Critical,
+        }
+    }
+}
+
 /// Represents the attack vector metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum AttackVector {
     #[strum(serialize = "N")]
     Network,
@@ -129,8 +188,9 @@ impl AttackVector {
 }
 
 /// Represents the attack complexity metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum AttackComplexity {
     #[strum(serialize = "L")]
     Low,
@@ -152,8 +212,9 @@ impl AttackComplexity {
 }
 
 /// Represents the privileges required metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum PrivilegesRequired {
     #[strum(serialize = "N")]
     None,
@@ -191,8 +252,9 @@ impl PrivilegesRequired {
 }
 
 /// Represents the user interaction metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum UserInteraction {
     #[strum(serialize = "N")]
     None,
@@ -214,8 +276,9 @@ impl UserInteraction {
 }
 
 /// Represents the scope metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Scope {
     #[strum(serialize = "U")]
     Unchanged,
@@ -233,8 +296,9 @@ impl Scope {
 }
 
 /// Represents the impact metrics (confidentiality, integrity, availability).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Impact {
     #[strum(serialize = "H")]
     High,
@@ -259,8 +323,9 @@ impl Impact {
 }
 
 /// Represents the exploit code maturity metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum ExploitCodeMaturity {
     #[strum(serialize = "U")]
     Unproven,
@@ -288,8 +353,9 @@ impl ExploitCodeMaturity {
 }
 
 /// Represents the remediation level metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum RemediationLevel {
     #[strum(serialize = "O")]
     OfficialFix,
@@ -317,8 +383,9 @@ impl RemediationLevel {
 }
 
 /// Represents the report confidence metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum ReportConfidence {
     #[strum(serialize = "U")]
     Unknown,
@@ -343,8 +410,9 @@ impl ReportConfidence {
 }
 
 /// Represents the security requirement metric.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum SecurityRequirement {
     #[strum(serialize = "L")]
     Low,
@@ -368,11 +436,157 @@ impl SecurityRequirement {
     }
 }
 
+/// A base score formula for CVSS v3.x, for experimenting with alternative scoring.
+///
+/// The default implementation is the specification's formula, i.e.
+/// [`CvssV3::calculated_base_score`], so an implementation only needs to override
+/// [`Scorer::base_score`] to change the formula. Use it with [`CvssV3::score_with`].
+pub trait Scorer {
+    /// Calculates the base score of `cvss`, or None if it cannot be scored.
+    fn base_score(&self, cvss: &CvssV3) -> Option<f64> {
+        cvss.calculated_base_score()
+    }
+}
+
+/// The [`Scorer`] of the CVSS v3.x specification.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpecScorer;
+
+impl Scorer for SpecScorer {}
+
+/// The variant of the environmental score formula applied for a scope change.
+///
+/// CVSS v3.1 changed the modified impact formula for a changed scope from
+/// `7.52 × (MISS - 0.029) - 3.25 × (MISS - 0.02)^15` to
+/// `7.52 × (MISS - 0.029) - 3.25 × (MISS × 0.9731 - 0.02)^13`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormulaVersion {
+    /// The CVSS v3.0 formula, with exponent 15.
+    V3_0,
+    /// The CVSS v3.1 formula, with exponent 13.
+    V3_1,
+}
+
+impl FormulaVersion {
+    /// Returns the exponent of the modified impact formula: 15 for v3.0 and 13 for v3.1.
+    pub fn exponent(&self) -> i32 {
+        match self {
+            FormulaVersion::V3_0 => 15,
+            FormulaVersion::V3_1 => 13,
+        }
+    }
+}
+
+/// The subscores and scores of a CVSS v3.0 or v3.1 vector, as returned by
+/// [`CvssV3::score_breakdown`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBreakdown {
+    /// The impact subscore, see [`CvssV3::impact_subscore`].
+    pub impact: f64,
+    /// The exploitability subscore, see [`CvssV3::exploitability_subscore`].
+    pub exploitability: f64,
+    /// The base score.
+    pub base: f64,
+    /// The temporal score, if any temporal metrics are present.
+    pub temporal: Option<f64>,
+    /// The environmental score, if any environmental metrics are present.
+    pub environmental: Option<f64>,
+    /// The abbreviations of the metrics that contributed to the scores, i.e. that are present
+    /// and not Not Defined, in specification order.
+    pub metrics: Vec<&'static str>,
+}
+
 impl CvssV3 {
     pub fn vector_string(&self) -> &str {
         &self.vector_string
     }
 
+    /// Consumes the object and returns its vector string without cloning it.
+    pub fn into_vector_string(self) -> String {
+        self.vector_string
+    }
+
+    /// Returns the metrics that are set, as `(abbreviation, value)` pairs in specification
+    /// order, e.g. `("AV", "N")`.
+    pub fn metrics(&self) -> Vec<(&'static str, String)> {
+        [
+            metric_pair("AV", &self.attack_vector),
+            metric_pair("AC", &self.attack_complexity),
+            metric_pair("PR", &self.privileges_required),
+            metric_pair("UI", &self.user_interaction),
+            metric_pair("S", &self.scope),
+            metric_pair("C", &self.confidentiality_impact),
+            metric_pair("I", &self.integrity_impact),
+            metric_pair("A", &self.availability_impact),
+            metric_pair("E", &self.exploit_code_maturity),
+            metric_pair("RL", &self.remediation_level),
+            metric_pair("RC", &self.report_confidence),
+            metric_pair("CR", &self.confidentiality_requirement),
+            metric_pair("IR", &self.integrity_requirement),
+            metric_pair("AR", &self.availability_requirement),
+            metric_pair("MAV", &self.modified_attack_vector),
+            metric_pair("MAC", &self.modified_attack_complexity),
+            metric_pair("MPR", &self.modified_privileges_required),
+            metric_pair("MUI", &self.modified_user_interaction),
+            metric_pair("MS", &self.modified_scope),
+            metric_pair("MC", &self.modified_confidentiality_impact),
+            metric_pair("MI", &self.modified_integrity_impact),
+            metric_pair("MA", &self.modified_availability_impact),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Builds the vector string from the metric fields, e.g.
+    /// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+    ///
+    /// Unlike the stored [`CvssV3::vector_string`], this reflects changes to the fields. Only
+    /// present metrics are written, in specification order, and Not Defined (`X`) metrics are
+    /// omitted like in the FIRST calculator; use [`CvssV3::to_vector_string_verbose`] to keep
+    /// them. The prefix is taken from the `version` field; objects without one use the prefix
+    /// of the stored vector string if it is `CVSS:3.0`, and `CVSS:3.1` otherwise.
+    pub fn to_vector_string(&self) -> String {
+        self.format_vector(true)
+    }
+
+    /// Builds the vector string from the metric fields like [`CvssV3::to_vector_string`], but
+    /// keeps Not Defined (`X`) metrics, e.g. `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X`.
+    pub fn to_vector_string_verbose(&self) -> String {
+        self.format_vector(false)
+    }
+
+    /// Builds the canonical form of the vector string, e.g. for deduplication or for comparison
+    /// against other tools.
+    ///
+    /// This is the same as [`CvssV3::to_vector_string`]: metrics are written in specification
+    /// order with canonical casing, and Not Defined (`X`) metrics are omitted. For example,
+    /// `CVSS:3.1/S:U/AV:N/AC:L/PR:N/UI:N/C:H/I:H/A:H/E:X` is normalized to
+    /// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`.
+    pub fn normalized_vector(&self) -> String {
+        self.to_vector_string()
+    }
+
+    /// Returns the `version` field, or for objects without one, the version of the stored
+    /// vector string if it is `CVSS:3.0`, and v3.1 otherwise.
+    fn effective_version(&self) -> VersionV3 {
+        match &self.version {
+            Some(version) => version.clone(),
+            None if self.vector_string.starts_with("CVSS:3.0") => VersionV3::V3_0,
+            None => VersionV3::V3_1,
+        }
+    }
+
+    fn format_vector(&self, skip_not_defined: bool) -> String {
+        let mut vector = format!("CVSS:{}", self.effective_version());
+        for (key, value) in self.metrics() {
+            if !(skip_not_defined && value == "X") {
+                vector.push_str(&format!("/{key}:{value}"));
+            }
+        }
+        vector
+    }
+
     pub fn base_score(&self) -> f64 {
         self.base_score
     }
@@ -387,42 +601,564 @@ impl CvssV3 {
         })
     }
 
-    /// Calculates the base score from the base metrics.
+    /// Parses a CVSS v3.0 or v3.1 vector string like [`CvssV3::from_str`], but without requiring
+    /// the base metrics, e.g. `CVSS:3.1/AV:N/AC:L`.
+    ///
+    /// Partial vectors cannot be scored, so the `calculated_*` methods return None for them.
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseError> {
+        // extract and validate version prefix
+        let (version, components_str) = prefix::extract_version_from_required_prefix(s)?;
+
+        // validate that the prefix version is either 3.0 or 3.1
+        prefix::validate_allowed_prefix_version(&version, &[Version::V3_0, Version::V3_1])?;
+
+        // map to tightened version enum
+        let parsed_version = match version {
+            Version::V3_0 => VersionV3::V3_0,
+            Version::V3_1 => VersionV3::V3_1,
+            _ => unreachable!("validated above"),
+        };
+
+        let mut cvss = CvssV3::empty(without_score_pseudo_metrics(s), parsed_version);
+
+        // Parse metrics, tracking the byte offset of each component for error reporting
+        let mut scores = ScorePseudoMetrics::new();
+        let mut offset = s.len() - components_str.len();
+        for component in components_str.split('/') {
+            let position = offset;
+            offset += component.len() + 1;
+            if component.is_empty() {
+                continue;
+            }
+
+            split_component(component)
+                .and_then(|(key, value)| cvss.set_component(&mut scores, &key, &value))
+                .map_err(|err| err.at(position))?;
+        }
+        cvss.set_scores(scores);
+
+        Ok(cvss)
+    }
+
+    /// Builds a `CvssV3` from a map of metric abbreviations to values, e.g. `"AV" => "N"`.
+    ///
+    /// Keys and values are matched case-insensitively, like in vector strings. All base metrics
+    /// are required. The vector string, the base score and severity, and the temporal and
+    /// environmental scores and severities (if any such metrics are present) are computed
+    /// from the metrics.
+    ///
+    /// # Errors
+    ///
+    /// * [`ParseError::InvalidPrefixVersion`] if `version` is not 3.0 or 3.1
+    /// * [`ParseError::UnknownMetric`] or [`ParseError::InvalidMetricValue`] for invalid entries
+    /// * [`ParseError::MissingRequiredMetric`] naming the first missing base metric
+    #[cfg(feature = "std")]
+    pub fn from_metric_map(
+        version: Version,
+        map: &HashMap<String, String>,
+    ) -> Result<CvssV3, ParseError> {
+        CvssV3::from_metric_pairs(version, map.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+    }
+
+    /// Builds a `CvssV3` from metric abbreviations and values, as described for
+    /// [`CvssV3::from_metric_map`].
+    pub(crate) fn from_metric_pairs<'a>(
+        version: Version,
+        pairs: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<CvssV3, ParseError> {
+        prefix::validate_allowed_prefix_version(&version, &[Version::V3_0, Version::V3_1])?;
+        let version_v3 = match version {
+            Version::V3_0 => VersionV3::V3_0,
+            Version::V3_1 => VersionV3::V3_1,
+            _ => unreachable!("validated above"),
+        };
+
+        let mut cvss = CvssV3::empty(format!("CVSS:{version}"), version_v3);
+        for (key, value) in pairs {
+            cvss.set_metric(&key.to_ascii_uppercase(), &value.to_ascii_uppercase())?;
+        }
+        cvss.complete()
+    }
+
+    /// Checks that all base metrics are present, then computes the vector string and the
+    /// scores from the metrics.
+    fn complete(mut self) -> Result<CvssV3, ParseError> {
+        if let Some(metric) = self.missing_base_metric() {
+            return Err(ParseError::MissingRequiredMetric {
+                metric: metric.to_string(),
+            });
+        }
+
+        self.vector_string = self.to_string();
+        self.update_scores();
+        Ok(self)
+    }
+
+    /// Returns whether all eight base metrics are present, so that the base score can be
+    /// calculated.
+    pub fn is_complete(&self) -> bool {
+        self.missing_base_metric().is_none()
+    }
+
+    /// Returns the abbreviation of the first missing base metric, in specification order.
+    fn missing_base_metric(&self) -> Option<&'static str> {
+        [
+            ("AV", self.attack_vector.is_none()),
+            ("AC", self.attack_complexity.is_none()),
+            ("PR", self.privileges_required.is_none()),
+            ("UI", self.user_interaction.is_none()),
+            ("S", self.scope.is_none()),
+            ("C", self.confidentiality_impact.is_none()),
+            ("I", self.integrity_impact.is_none()),
+            ("A", self.availability_impact.is_none()),
+        ]
+        .into_iter()
+        .find_map(|(metric, missing)| missing.then_some(metric))
+    }
+
+    /// Populates the stored score and severity fields from the calculated scores.
+    /// The temporal and environmental fields are only populated if such metrics are present.
+    fn update_scores(&mut self) {
+        if let Some(score) = self.calculated_base_score() {
+            self.base_score = score;
+            self.base_severity = Severity::from_score(score);
+        }
+
+        if self.has_temporal_metrics() {
+            self.temporal_score = self.calculated_temporal_score();
+            self.temporal_severity = self.temporal_score.map(Severity::from_score);
+        }
+
+        if self.has_environmental_metrics() {
+            self.environmental_score = self.calculated_environmental_score();
+            self.environmental_severity = self.environmental_score.map(Severity::from_score);
+        }
+    }
+
+    /// Returns whether any temporal metric is present, even if Not Defined.
+    fn has_temporal_metrics(&self) -> bool {
+        self.exploit_code_maturity.is_some()
+            || self.remediation_level.is_some()
+            || self.report_confidence.is_some()
+    }
+
+    /// Returns whether any environmental metric is present, even if Not Defined.
+    fn has_environmental_metrics(&self) -> bool {
+        self.confidentiality_requirement.is_some()
+            || self.integrity_requirement.is_some()
+            || self.availability_requirement.is_some()
+            || self.modified_attack_vector.is_some()
+            || self.modified_attack_complexity.is_some()
+            || self.modified_privileges_required.is_some()
+            || self.modified_user_interaction.is_some()
+            || self.modified_scope.is_some()
+            || self.modified_confidentiality_impact.is_some()
+            || self.modified_integrity_impact.is_some()
+            || self.modified_availability_impact.is_some()
+    }
+
+    /// Column names of [`CvssV3::feature_vector`], in order.
+    pub const FEATURE_COLUMNS: [&'static str; 22] = [
+        "AV", "AC", "PR", "UI", "S", "C", "I", "A", "E", "RL", "RC", "CR", "IR", "AR", "MAV",
+        "MAC", "MPR", "MUI", "MS", "MC", "MI", "MA",
+    ];
+
+    /// Encodes the metrics as a fixed-size numeric feature vector, e.g. as input for a model.
+    ///
+    /// Each column holds the metric's weight from the specification (its `score()`), in the
+    /// order given by [`CvssV3::FEATURE_COLUMNS`]. Absent metrics are encoded as `-1.0`, which no
+    /// weight can take, so that they differ from present metrics with a weight of `0.0`, like
+    /// `C:N`. Modified metrics set to Not Defined (`X`) are encoded like absent ones. Scope has
+    /// no weight of its own and is encoded as `1.0` when changed and `0.0` otherwise; the
+    /// privileges required weights take the (modified) scope into account.
+    pub fn feature_vector(&self) -> Vec<f64> {
+        const ABSENT: f64 = -1.0;
+        fn weight<T>(metric: &Option<T>, score: impl Fn(&T) -> f64) -> f64 {
+            metric.as_ref().map_or(ABSENT, score)
+        }
+        fn modified<T: PartialEq>(
+            metric: &Option<T>,
+            not_defined: T,
+            score: impl Fn(&T) -> f64,
+        ) -> f64 {
+            metric
+                .as_ref()
+                .filter(|value| **value != not_defined)
+                .map_or(ABSENT, score)
+        }
+        let scope_changed = self.scope.as_ref().is_some_and(Scope::is_changed);
+        let modified_scope_changed = self.effective_scope().is_changed();
+        let scope_flag = |s: &Scope| if s.is_changed() { 1.0 } else { 0.0 };
+
+        vec![
+            weight(&self.attack_vector, AttackVector::score),
+            weight(&self.attack_complexity, AttackComplexity::score),
+            weight(&self.privileges_required, |pr| pr.score(scope_changed)),
+            weight(&self.user_interaction, UserInteraction::score),
+            weight(&self.scope, scope_flag),
+            weight(&self.confidentiality_impact, Impact::score),
+            weight(&self.integrity_impact, Impact::score),
+            weight(&self.availability_impact, Impact::score),
+            weight(&self.exploit_code_maturity, ExploitCodeMaturity::score),
+            weight(&self.remediation_level, RemediationLevel::score),
+            weight(&self.report_confidence, ReportConfidence::score),
+            weight(
+                &self.confidentiality_requirement,
+                SecurityRequirement::score,
+            ),
+            weight(&self.integrity_requirement, SecurityRequirement::score),
+            weight(&self.availability_requirement, SecurityRequirement::score),
+            modified(
+                &self.modified_attack_vector,
+                AttackVector::NotDefined,
+                AttackVector::score,
+            ),
+            modified(
+                &self.modified_attack_complexity,
+                AttackComplexity::NotDefined,
+                AttackComplexity::score,
+            ),
+            modified(
+                &self.modified_privileges_required,
+                PrivilegesRequired::NotDefined,
+                |pr| pr.score(modified_scope_changed),
+            ),
+            modified(
+                &self.modified_user_interaction,
+                UserInteraction::NotDefined,
+                UserInteraction::score,
+            ),
+            modified(&self.modified_scope, Scope::NotDefined, scope_flag),
+            modified(
+                &self.modified_confidentiality_impact,
+                Impact::NotDefined,
+                Impact::score,
+            ),
+            modified(
+                &self.modified_integrity_impact,
+                Impact::NotDefined,
+                Impact::score,
+            ),
+            modified(
+                &self.modified_availability_impact,
+                Impact::NotDefined,
+                Impact::score,
+            ),
+        ]
+    }
+
+    /// Returns the weight of each present base metric relative to its worst-case weight, in
+    /// specification order.
+    ///
+    /// A value of 1.0 means the metric takes its most severe value. Scope is not included, as it
+    /// has no weight of its own.
+    pub(crate) fn base_contributions(&self) -> Vec<(&'static str, f64)> {
+        let scope_changed = self.scope.as_ref().is_some_and(Scope::is_changed);
+        let impact_max = Impact::High.score();
+
+        [
+            (
+                "AV",
+                self.attack_vector
+                    .as_ref()
+                    .map(|av| av.score() / AttackVector::Network.score()),
+            ),
+            (
+                "AC",
+                self.attack_complexity
+                    .as_ref()
+                    .map(|ac| ac.score() / AttackComplexity::Low.score()),
+            ),
+            (
+                "PR",
+                self.privileges_required.as_ref().map(|pr| {
+                    pr.score(scope_changed) / PrivilegesRequired::None.score(scope_changed)
+                }),
+            ),
+            (
+                "UI",
+                self.user_interaction
+                    .as_ref()
+                    .map(|ui| ui.score() / UserInteraction::None.score()),
+            ),
+            (
+                "C",
+                self.confidentiality_impact
+                    .as_ref()
+                    .map(|c| c.score() / impact_max),
+            ),
+            (
+                "I",
+                self.integrity_impact
+                    .as_ref()
+                    .map(|i| i.score() / impact_max),
+            ),
+            (
+                "A",
+                self.availability_impact
+                    .as_ref()
+                    .map(|a| a.score() / impact_max),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(metric, contribution)| Some((metric, contribution?)))
+        .collect()
+    }
+
+    /// Returns the variant of the environmental score formula applied to this object.
+    ///
+    /// This follows the `version` field. Objects without one use the version of the prefix of
+    /// the stored vector string if it is `CVSS:3.0`, and the v3.1 formula otherwise, like
+    /// [`CvssV3::to_vector_string`].
+    pub fn environmental_formula(&self) -> FormulaVersion {
+        match self.effective_version() {
+            VersionV3::V3_0 => FormulaVersion::V3_0,
+            VersionV3::V3_1 => FormulaVersion::V3_1,
+        }
+    }
+
+    /// Returns the scope used for environmental scoring.
+    ///
+    /// This is the modified scope (MS) if it is present and not `NotDefined`, and the base
+    /// scope (S) otherwise. It selects both the impact formula and the privileges required
+    /// weight (e.g. 0.68 instead of 0.62 for `Low`) of the environmental score.
+    /// Returns [`Scope::NotDefined`] if neither is present.
+    pub fn effective_scope(&self) -> Scope {
+        self.modified_scope
+            .as_ref()
+            .filter(|v| !matches!(v, Scope::NotDefined))
+            .or(self.scope.as_ref())
+            .cloned()
+            .unwrap_or(Scope::NotDefined)
+    }
+
+    /// Returns whether the stored `base_severity` matches the severity band of the calculated
+    /// base score. Returns false if the base score cannot be calculated.
+    ///
+    /// This flags records where the severity is wrong for the metrics, regardless of the stored
+    /// `base_score`.
+    pub fn base_severity_consistent(&self) -> bool {
+        self.calculated_base_severity()
+            .is_some_and(|severity| severity == self.base_severity)
+    }
+
+    /// Returns the qualitative severity rating of `calculated_base_score()`.
     /// Returns None if required base metrics are missing.
-    pub fn calculated_base_score(&self) -> Option<f64> {
-        // All base metrics are required
+    pub fn calculated_base_severity(&self) -> Option<Severity> {
+        self.calculated_base_score().map(Severity::from_score)
+    }
+
+    /// Returns the qualitative severity rating of `calculated_temporal_score()`.
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_temporal_severity(&self) -> Option<Severity> {
+        self.calculated_temporal_score().map(Severity::from_score)
+    }
+
+    /// Returns the qualitative severity rating of `calculated_environmental_score()`.
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_environmental_severity(&self) -> Option<Severity> {
+        self.calculated_environmental_score()
+            .map(Severity::from_score)
+    }
+
+    /// Calculates the base score as if the scope metric were `scope`, without modifying `self`.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// The scope affects both the weight of the privileges required and the impact subscore.
+    pub fn score_with_scope(&self, scope: Scope) -> Option<f64> {
+        CvssV3 {
+            scope: Some(scope),
+            ..self.clone()
+        }
+        .calculated_base_score()
+    }
+
+    /// Calculates the base score with a custom [`Scorer`] instead of the specification's formula.
+    pub fn score_with<S: Scorer>(&self, scorer: &S) -> Option<f64> {
+        scorer.base_score(self)
+    }
+
+    /// Calculates the exploitability subscore from the base metrics.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// The subscore is not rounded.
+    pub fn exploitability_subscore(&self) -> Option<f64> {
         let av = self.attack_vector.as_ref()?;
         let ac = self.attack_complexity.as_ref()?;
         let pr = self.privileges_required.as_ref()?;
         let ui = self.user_interaction.as_ref()?;
-        let scope = self.scope.as_ref()?;
+        let scope_changed = self.scope.as_ref()?.is_changed();
+
+        Some(8.22 * av.score() * ac.score() * pr.score(scope_changed) * ui.score())
+    }
+
+    /// Calculates the Impact Sub Score (ISS), `1 - ((1 - C) × (1 - I) × (1 - A))`, from the
+    /// confidentiality, integrity and availability impacts.
+    /// Returns None if any of them is missing.
+    ///
+    /// This is the impact before the scope-dependent scaling of [`CvssV3::impact_subscore`].
+    pub fn impact_base(&self) -> Option<f64> {
         let c = self.confidentiality_impact.as_ref()?;
         let i = self.integrity_impact.as_ref()?;
         let a = self.availability_impact.as_ref()?;
 
-        let scope_changed = scope.is_changed();
+        Some(1.0 - ((1.0 - c.score()) * (1.0 - i.score()) * (1.0 - a.score())))
+    }
 
-        // Calculate exploitability sub-score
-        let exploitability = 8.22 * av.score() * ac.score() * pr.score(scope_changed) * ui.score();
+    /// Calculates the impact subscore from the base metrics, which depends on the scope.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// The subscore is not rounded and may be negative, in which case the base score is 0.0.
+    pub fn impact_subscore(&self) -> Option<f64> {
+        let scope_changed = self.scope.as_ref()?.is_changed();
+        let iss = self.impact_base()?;
+
+        // The impact formula is the same for v3.0 and v3.1
+        let impact = if scope_changed {
+            7.52 * (iss - 0.029) - 3.25 * float::powf(iss - 0.02, 15.0)
+        } else {
+            6.42 * iss
+        };
 
-        // Calculate impact sub-score
-        let impact_sub = 1.0 - ((1.0 - c.score()) * (1.0 - i.score()) * (1.0 - a.score()));
+        Some(impact)
+    }
 
-        // Calculate ISS (Impact Sub Score)
-        // Base score formula is the same for v3.0 and v3.1
-        let iss = if scope_changed {
-            7.52 * (impact_sub - 0.029) - 3.25 * (impact_sub - 0.02).powf(15.0)
-        } else {
-            6.42 * impact_sub
+    /// Returns the subscores and scores of the vector, with the metrics that contributed to
+    /// them. Returns None if required base metrics are missing.
+    ///
+    /// The temporal and environmental scores are only included if such metrics are present,
+    /// like in the scores populated by [`CvssV3Builder::build`].
+    pub fn score_breakdown(&self) -> Option<ScoreBreakdown> {
+        Some(ScoreBreakdown {
+            impact: self.impact_subscore()?,
+            exploitability: self.exploitability_subscore()?,
+            base: self.calculated_base_score()?,
+            temporal: self
+                .has_temporal_metrics()
+                .then(|| self.calculated_temporal_score())
+                .flatten(),
+            environmental: self
+                .has_environmental_metrics()
+                .then(|| self.calculated_environmental_score())
+                .flatten(),
+            metrics: self
+                .metrics()
+                .into_iter()
+                .filter(|(_, value)| value != "X")
+                .map(|(metric, _)| metric)
+                .collect(),
+        })
+    }
+
+    /// Returns whether the stored `exploitability_score` and `impact_score` match the calculated
+    /// subscores, rounded to one decimal place like NVD does.
+    ///
+    /// Absent stored subscores are not checked. Returns false if a subscore is stored, but cannot
+    /// be calculated.
+    pub fn subscores_consistent(&self) -> bool {
+        let matches = |stored: Option<f64>, calculated: Option<f64>| {
+            stored.is_none_or(|stored| {
+                calculated.is_some_and(|c| float::round(c * 10.0) / 10.0 == stored)
+            })
         };
+        matches(self.exploitability_score, self.exploitability_subscore())
+            && matches(self.impact_score, self.impact_subscore())
+    }
 
-        // Calculate base score
-        let score = if iss <= 0.0 {
+    /// Calculates the base score from the base metrics.
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_base_score(&self) -> Option<f64> {
+        self.unrounded_base_score().map(Self::roundup)
+    }
+
+    /// Returns the lowest and highest base score that the vector can have, by filling in the
+    /// least and most severe values of the base metrics that are missing, e.g. `(0.0, 9.8)` for
+    /// `AV:N/AC:L/PR:N/UI:N/S:U`.
+    /// Returns None if no base metric is present.
+    ///
+    /// For complete vectors, both bounds are the base score.
+    pub fn score_range(&self) -> Option<(f64, f64)> {
+        if self.attack_vector.is_none()
+            && self.attack_complexity.is_none()
+            && self.privileges_required.is_none()
+            && self.user_interaction.is_none()
+            && self.scope.is_none()
+            && self.confidentiality_impact.is_none()
+            && self.integrity_impact.is_none()
+            && self.availability_impact.is_none()
+        {
+            return None;
+        }
+
+        // For a given scope, the base score grows with the severity of every other base metric,
+        // so the bounds are reached by filling in the least and most severe values
+        let fill = |scope: Scope,
+                    av: AttackVector,
+                    ac: AttackComplexity,
+                    pr: PrivilegesRequired,
+                    ui: UserInteraction,
+                    impact: Impact| CvssV3 {
+            attack_vector: self.attack_vector.clone().or(Some(av)),
+            attack_complexity: self.attack_complexity.clone().or(Some(ac)),
+            privileges_required: self.privileges_required.clone().or(Some(pr)),
+            user_interaction: self.user_interaction.clone().or(Some(ui)),
+            scope: Some(scope),
+            confidentiality_impact: self.confidentiality_impact.clone().or(Some(impact.clone())),
+            integrity_impact: self.integrity_impact.clone().or(Some(impact.clone())),
+            availability_impact: self.availability_impact.clone().or(Some(impact)),
+            ..self.clone()
+        };
+
+        let scopes = match &self.scope {
+            Some(scope) => vec![scope.clone()],
+            None => vec![Scope::Unchanged, Scope::Changed],
+        };
+        scopes
+            .into_iter()
+            .flat_map(|scope| {
+                let least = fill(
+                    scope.clone(),
+                    AttackVector::Physical,
+                    AttackComplexity::High,
+                    PrivilegesRequired::High,
+                    UserInteraction::Required,
+                    Impact::None,
+                );
+                let most = fill(
+                    scope,
+                    AttackVector::Network,
+                    AttackComplexity::Low,
+                    PrivilegesRequired::None,
+                    UserInteraction::None,
+                    Impact::High,
+                );
+                [least, most]
+            })
+            .filter_map(|candidate| candidate.calculated_base_score())
+            .fold(None, |range, score| match range {
+                None => Some((score, score)),
+                Some((min, max)) => Some((f64::min(min, score), f64::max(max, score))),
+            })
+    }
+
+    /// Calculates the base score before it is rounded up to one decimal place, i.e.
+    /// `min(impact + exploitability, 10)` (scaled by 1.08 if the scope is changed), or 0.0 if
+    /// the impact is not positive.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// [`CvssV3::calculated_base_score`] is this value after the specification's `Roundup`.
+    pub fn unrounded_base_score(&self) -> Option<f64> {
+        // All base metrics are required
+        let exploitability = self.exploitability_subscore()?;
+        let impact = self.impact_subscore()?;
+        let scope_changed = self.scope.as_ref()?.is_changed();
+
+        let score = if impact <= 0.0 {
             0.0
         } else if scope_changed {
-            Self::roundup(f64::min(1.08 * (exploitability + iss), 10.0))
+            f64::min(1.08 * (exploitability + impact), 10.0)
         } else {
-            Self::roundup(f64::min(exploitability + iss, 10.0))
+            f64::min(exploitability + impact, 10.0)
         };
 
         Some(score)
@@ -433,6 +1169,16 @@ impl CvssV3 {
     pub fn calculated_temporal_score(&self) -> Option<f64> {
         let base_score = self.calculated_base_score()?;
 
+        let score = Self::roundup(base_score * self.temporal_multiplier());
+        Some(score)
+    }
+
+    /// Returns the product of the temporal metric multipliers, `E × RL × RC`, by which the
+    /// temporal score scales the base score (before rounding up).
+    ///
+    /// Absent and Not Defined metrics have a multiplier of 1.0, so this is 1.0 without temporal
+    /// metrics.
+    pub fn temporal_multiplier(&self) -> f64 {
         // Temporal metrics default to 1.0 (NotDefined) if not present
         let e = self
             .exploit_code_maturity
@@ -450,8 +1196,7 @@ impl CvssV3 {
             .map(|m| m.score())
             .unwrap_or(1.0);
 
-        let score = Self::roundup(base_score * e * rl * rc);
-        Some(score)
+        e * rl * rc
     }
 
     /// Calculates the environmental score from base, temporal, and environmental metrics.
@@ -462,10 +1207,7 @@ impl CvssV3 {
         let ac = self.attack_complexity.as_ref()?;
         let pr = self.privileges_required.as_ref()?;
         let ui = self.user_interaction.as_ref()?;
-        let scope = self.scope.as_ref()?;
-        let c = self.confidentiality_impact.as_ref()?;
-        let i = self.integrity_impact.as_ref()?;
-        let a = self.availability_impact.as_ref()?;
+        self.scope.as_ref()?;
 
         // Modified metrics: if not present or set to NotDefined (X), fall back to base metric
         let mav = self
@@ -488,67 +1230,29 @@ impl CvssV3 {
             .as_ref()
             .filter(|v| !matches!(v, UserInteraction::NotDefined))
             .unwrap_or(ui);
-        let ms = self
-            .modified_scope
-            .as_ref()
-            .filter(|v| !matches!(v, Scope::NotDefined))
-            .unwrap_or(scope);
-        let mc = self
-            .modified_confidentiality_impact
-            .as_ref()
-            .filter(|v| !matches!(v, Impact::NotDefined))
-            .unwrap_or(c);
-        let mi = self
-            .modified_integrity_impact
-            .as_ref()
-            .filter(|v| !matches!(v, Impact::NotDefined))
-            .unwrap_or(i);
-        let ma = self
-            .modified_availability_impact
-            .as_ref()
-            .filter(|v| !matches!(v, Impact::NotDefined))
-            .unwrap_or(a);
-
-        // Security requirements default to 1.0 (Medium/NotDefined)
-        let cr = self
-            .confidentiality_requirement
-            .as_ref()
-            .map(|r| r.score())
-            .unwrap_or(1.0);
-        let ir = self
-            .integrity_requirement
-            .as_ref()
-            .map(|r| r.score())
-            .unwrap_or(1.0);
-        let ar = self
-            .availability_requirement
-            .as_ref()
-            .map(|r| r.score())
-            .unwrap_or(1.0);
 
-        let scope_changed = ms.is_changed();
+        // The modified scope drives both the MPR weight and the impact formula
+        let scope_changed = self.effective_scope().is_changed();
 
         // Calculate modified exploitability
         let m_exploitability =
             8.22 * mav.score() * mac.score() * mpr.score(scope_changed) * mui.score();
 
-        // Calculate modified impact
-        let m_impact_sub = f64::min(
-            1.0 - ((1.0 - cr * mc.score()) * (1.0 - ir * mi.score()) * (1.0 - ar * ma.score())),
-            0.915,
-        );
+        // Calculate modified impact (the base impact metrics are required)
+        let m_impact_sub = f64::min(self.uncapped_modified_impact_subscore()?, 0.915);
 
         // Calculate modified ISS
         // CVSS v3.1 uses a different formula than v3.0
         let m_iss = if scope_changed {
-            match self.version {
-                Some(VersionV3::V3_1) => {
+            match self.environmental_formula() {
+                FormulaVersion::V3_1 => {
                     // v3.1: 7.52 × (MISS - 0.029) - 3.25 × (MISS × 0.9731 - 0.02)^13
-                    7.52 * (m_impact_sub - 0.029) - 3.25 * (m_impact_sub * 0.9731 - 0.02).powf(13.0)
+                    7.52 * (m_impact_sub - 0.029)
+                        - 3.25 * float::powf(m_impact_sub * 0.9731 - 0.02, 13.0)
                 }
-                _ => {
+                FormulaVersion::V3_0 => {
                     // v3.0: 7.52 × (MISS - 0.029) - 3.25 × (MISS - 0.02)^15
-                    7.52 * (m_impact_sub - 0.029) - 3.25 * (m_impact_sub - 0.02).powf(15.0)
+                    7.52 * (m_impact_sub - 0.029) - 3.25 * float::powf(m_impact_sub - 0.02, 15.0)
                 }
             }
         } else {
@@ -588,6 +1292,58 @@ impl CvssV3 {
         Some(score)
     }
 
+    /// Returns whether the modified impact sub score (MISS) of the environmental score exceeds its
+    /// cap of 0.915, in which case raising the security requirements or modified impacts further
+    /// does not change the score. Returns false if required base metrics are missing.
+    pub fn miss_capped(&self) -> bool {
+        self.uncapped_modified_impact_subscore()
+            .is_some_and(|miss| miss > 0.915)
+    }
+
+    /// Calculates the modified impact sub score (MISS) before it is capped at 0.915.
+    /// Returns None if required base impact metrics are missing.
+    fn uncapped_modified_impact_subscore(&self) -> Option<f64> {
+        let c = self.confidentiality_impact.as_ref()?;
+        let i = self.integrity_impact.as_ref()?;
+        let a = self.availability_impact.as_ref()?;
+
+        // Modified metrics: if not present or set to NotDefined (X), fall back to base metric
+        let mc = self
+            .modified_confidentiality_impact
+            .as_ref()
+            .filter(|v| !matches!(v, Impact::NotDefined))
+            .unwrap_or(c);
+        let mi = self
+            .modified_integrity_impact
+            .as_ref()
+            .filter(|v| !matches!(v, Impact::NotDefined))
+            .unwrap_or(i);
+        let ma = self
+            .modified_availability_impact
+            .as_ref()
+            .filter(|v| !matches!(v, Impact::NotDefined))
+            .unwrap_or(a);
+
+        // Security requirements default to 1.0 (Medium/NotDefined)
+        let cr = self
+            .confidentiality_requirement
+            .as_ref()
+            .map(|r| r.score())
+            .unwrap_or(1.0);
+        let ir = self
+            .integrity_requirement
+            .as_ref()
+            .map(|r| r.score())
+            .unwrap_or(1.0);
+        let ar = self
+            .availability_requirement
+            .as_ref()
+            .map(|r| r.score())
+            .unwrap_or(1.0);
+
+        Some(1.0 - ((1.0 - cr * mc.score()) * (1.0 - ir * mi.score()) * (1.0 - ar * ma.score())))
+    }
+
     /// Rounds up to 1 decimal place as per CVSS v3 specification.
     ///
     /// Per the CVSS v3 spec, to avoid floating point precision issues,
@@ -595,33 +1351,16 @@ impl CvssV3 {
     /// This ensures consistent rounding across different implementations.
     fn roundup(value: f64) -> f64 {
         // Handle floating point precision by normalizing to integer first
-        let int_input = (value * 100000.0).round() as i64;
+        let int_input = float::round(value * 100000.0) as i64;
         let normalized = int_input as f64 / 100000.0;
-        (normalized * 10.0).ceil() / 10.0
+        float::ceil(normalized * 10.0) / 10.0
     }
-}
-
-impl FromStr for CvssV3 {
-    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // extract and validate version prefix
-        let (version, components_str) = prefix::extract_version_from_required_prefix(s)?;
-
-        // validate that the prefix version is either 3.0 or 3.1
-        prefix::validate_allowed_prefix_version(&version, &[Version::V3_0, Version::V3_1])?;
-
-        // map to tightened version enum
-        let parsed_version = match version {
-            Version::V3_0 => VersionV3::V3_0,
-            Version::V3_1 => VersionV3::V3_1,
-            _ => unreachable!("validated above"),
-        };
-
-        // Initialize a CvssV3 with empty fields
-        let mut cvss = CvssV3 {
-            vector_string: s.to_string(),
-            version: Some(parsed_version),
+    /// Creates a `CvssV3` of the given version without any metrics set.
+    pub(crate) fn empty(vector_string: String, version: VersionV3) -> Self {
+        CvssV3 {
+            vector_string,
+            version: Some(version),
             base_score: 0.0,
             base_severity: Severity::None,
             attack_vector: None,
@@ -632,6 +1371,8 @@ impl FromStr for CvssV3 {
             confidentiality_impact: None,
             integrity_impact: None,
             availability_impact: None,
+            exploitability_score: None,
+            impact_score: None,
             temporal_score: None,
             temporal_severity: None,
             exploit_code_maturity: None,
@@ -650,155 +1391,331 @@ impl FromStr for CvssV3 {
             modified_confidentiality_impact: None,
             modified_integrity_impact: None,
             modified_availability_impact: None,
-        };
+        }
+    }
 
-        // Parse metrics
-        for component in components_str.split('/') {
-            if component.is_empty() {
-                continue;
-            }
+    /// Like [`CvssV3::set_metric`], but also accepts the `BASESCORE`, `BASESEVERITY` and
+    /// `TEMPORALSCORE` pseudo-metrics, which are collected in `scores`.
+    pub(crate) fn set_component(
+        &mut self,
+        scores: &mut ScorePseudoMetrics<Severity>,
+        key: &str,
+        value: &str,
+    ) -> Result<(), ParseError> {
+        if !scores.parse(key, value)? {
+            self.set_metric(key, value)?;
+        }
+        Ok(())
+    }
 
-            let mut parts = component.split(':');
-            let key = parts
-                .next()
-                .ok_or_else(|| ParseError::InvalidComponent {
-                    component: component.to_string(),
-                })?
-                .to_ascii_uppercase();
-            let value = parts
-                .next()
-                .ok_or_else(|| ParseError::InvalidComponent {
-                    component: component.to_string(),
-                })?
-                .to_ascii_uppercase();
-
-            // Check for extra colons
-            if parts.next().is_some() {
-                return Err(ParseError::InvalidComponent {
-                    component: component.to_string(),
-                });
-            }
+    /// Populates the stored score fields from the score pseudo-metrics. Without an explicit
+    /// `baseSeverity`, the base severity is derived from `baseScore`.
+    pub(crate) fn set_scores(&mut self, scores: ScorePseudoMetrics<Severity>) {
+        if let Some(score) = scores.base_score {
+            self.base_score = score;
+            self.base_severity = Severity::from_score(score);
+        }
+        if let Some(severity) = scores.base_severity {
+            self.base_severity = severity;
+        }
+        if scores.temporal_score.is_some() {
+            self.temporal_score = scores.temporal_score;
+        }
+    }
 
-            match key.as_str() {
-                // Base metrics
-                "AV" => parse_metric(&mut cvss.attack_vector, &value, &key)?,
-                "AC" => parse_metric(&mut cvss.attack_complexity, &value, &key)?,
-                "PR" => parse_metric(&mut cvss.privileges_required, &value, &key)?,
-                "UI" => parse_metric(&mut cvss.user_interaction, &value, &key)?,
-                "S" => parse_metric(&mut cvss.scope, &value, &key)?,
-                "C" => parse_metric(&mut cvss.confidentiality_impact, &value, &key)?,
-                "I" => parse_metric(&mut cvss.integrity_impact, &value, &key)?,
-                "A" => parse_metric(&mut cvss.availability_impact, &value, &key)?,
-                // Temporal metrics
-                "E" => parse_metric(&mut cvss.exploit_code_maturity, &value, &key)?,
-                "RL" => parse_metric(&mut cvss.remediation_level, &value, &key)?,
-                "RC" => parse_metric(&mut cvss.report_confidence, &value, &key)?,
-                // Environmental metrics
-                "CR" => parse_metric(&mut cvss.confidentiality_requirement, &value, &key)?,
-                "IR" => parse_metric(&mut cvss.integrity_requirement, &value, &key)?,
-                "AR" => parse_metric(&mut cvss.availability_requirement, &value, &key)?,
-                // Modified metrics
-                "MAV" => parse_metric(&mut cvss.modified_attack_vector, &value, &key)?,
-                "MAC" => parse_metric(&mut cvss.modified_attack_complexity, &value, &key)?,
-                "MPR" => parse_metric(&mut cvss.modified_privileges_required, &value, &key)?,
-                "MUI" => parse_metric(&mut cvss.modified_user_interaction, &value, &key)?,
-                "MS" => parse_metric(&mut cvss.modified_scope, &value, &key)?,
-                "MC" => parse_metric(&mut cvss.modified_confidentiality_impact, &value, &key)?,
-                "MI" => parse_metric(&mut cvss.modified_integrity_impact, &value, &key)?,
-                "MA" => parse_metric(&mut cvss.modified_availability_impact, &value, &key)?,
-                _ => {
-                    return Err(ParseError::UnknownMetric { metric: key });
-                }
+    /// Parses `value` and stores it in the metric field identified by the abbreviation `key`.
+    /// Both are expected in uppercase.
+    pub(crate) fn set_metric(&mut self, key: &str, value: &str) -> Result<(), ParseError> {
+        match key {
+            // Base metrics
+            "AV" => parse_metric(&mut self.attack_vector, value, key)?,
+            "AC" => parse_metric(&mut self.attack_complexity, value, key)?,
+            "PR" => parse_metric(&mut self.privileges_required, value, key)?,
+            "UI" => parse_metric(&mut self.user_interaction, value, key)?,
+            "S" => parse_metric(&mut self.scope, value, key)?,
+            "C" => parse_metric(&mut self.confidentiality_impact, value, key)?,
+            "I" => parse_metric(&mut self.integrity_impact, value, key)?,
+            "A" => parse_metric(&mut self.availability_impact, value, key)?,
+            // Temporal metrics
+            "E" => parse_metric(&mut self.exploit_code_maturity, value, key)?,
+            "RL" => parse_metric(&mut self.remediation_level, value, key)?,
+            "RC" => parse_metric(&mut self.report_confidence, value, key)?,
+            // Environmental metrics
+            "CR" => parse_metric(&mut self.confidentiality_requirement, value, key)?,
+            "IR" => parse_metric(&mut self.integrity_requirement, value, key)?,
+            "AR" => parse_metric(&mut self.availability_requirement, value, key)?,
+            // Modified metrics
+            "MAV" => parse_metric(&mut self.modified_attack_vector, value, key)?,
+            "MAC" => parse_metric(&mut self.modified_attack_complexity, value, key)?,
+            "MPR" => parse_metric(&mut self.modified_privileges_required, value, key)?,
+            "MUI" => parse_metric(&mut self.modified_user_interaction, value, key)?,
+            "MS" => parse_metric(&mut self.modified_scope, value, key)?,
+            "MC" => parse_metric(&mut self.modified_confidentiality_impact, value, key)?,
+            "MI" => parse_metric(&mut self.modified_integrity_impact, value, key)?,
+            "MA" => parse_metric(&mut self.modified_availability_impact, value, key)?,
+            _ => {
+                return Err(ParseError::UnknownMetric {
+                    metric: key.to_string(),
+                    position: None,
+                });
             }
         }
+        Ok(())
+    }
+}
 
-        Ok(cvss)
+impl FromStr for CvssV3 {
+    type Err = ParseError;
+
+    /// Parses a CVSS v3.0 or v3.1 vector string.
+    ///
+    /// The scores are not calculated. As some tools append the stored scores to the vector, the
+    /// `baseScore`, `baseSeverity` and `temporalScore` pseudo-metrics are accepted and populate
+    /// the corresponding fields, but are removed from the stored vector string. `baseScore` also
+    /// sets the base severity, unless `baseSeverity` is present as well.
+    ///
+    /// All base metrics are required; see [`CvssV3::from_str_lenient`] for partial vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cvss = CvssV3::from_str_lenient(s)?;
+        match cvss.missing_base_metric() {
+            Some(metric) => Err(ParseError::MissingRequiredMetric {
+                metric: metric.to_string(),
+            }),
+            None => Ok(cvss),
+        }
     }
 }
 
 impl fmt::Display for CvssV3 {
+    /// Formats the metrics as a vector string, see [`CvssV3::to_vector_string`].
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Determine version from the stored vector_string if possible, default to 3.1
-        let version = if self.vector_string.starts_with("CVSS:3.0") {
-            "3.0"
-        } else {
-            "3.1"
-        };
+        f.write_str(&self.to_vector_string())
+    }
+}
 
-        write!(f, "CVSS:{}", version)?;
+/// Objects are compared by their calculated base scores, falling back to the stored base score
+/// if the vector is incomplete (see [`CvssV3::calculated_base_score`]), and then by their
+/// normalized vector strings (see [`CvssV3::normalized_vector`]).
+///
+/// Objects are equal if they have the same score and metrics, even if their original vector
+/// strings list the metrics in a different order or write out Not Defined metrics. Scores are
+/// compared with [`f64::total_cmp`], so the order is total and a `Vec<CvssV3>` can be sorted
+/// directly.
+impl PartialEq for CvssV3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
 
-        // Base metrics
-        if let Some(av) = &self.attack_vector {
-            write!(f, "/AV:{}", av)?;
-        }
-        if let Some(ac) = &self.attack_complexity {
-            write!(f, "/AC:{}", ac)?;
-        }
-        if let Some(pr) = &self.privileges_required {
-            write!(f, "/PR:{}", pr)?;
-        }
-        if let Some(ui) = &self.user_interaction {
-            write!(f, "/UI:{}", ui)?;
-        }
-        if let Some(s) = &self.scope {
-            write!(f, "/S:{}", s)?;
-        }
-        if let Some(c) = &self.confidentiality_impact {
-            write!(f, "/C:{}", c)?;
-        }
-        if let Some(i) = &self.integrity_impact {
-            write!(f, "/I:{}", i)?;
-        }
-        if let Some(a) = &self.availability_impact {
-            write!(f, "/A:{}", a)?;
-        }
+impl Eq for CvssV3 {}
 
-        // Temporal metrics
-        if let Some(e) = &self.exploit_code_maturity {
-            write!(f, "/E:{}", e)?;
-        }
-        if let Some(rl) = &self.remediation_level {
-            write!(f, "/RL:{}", rl)?;
-        }
-        if let Some(rc) = &self.report_confidence {
-            write!(f, "/RC:{}", rc)?;
-        }
+/// Hashes the normalized vector string (see [`CvssV3::normalized_vector`]).
+///
+/// The floating-point score fields are intentionally excluded from the hash.
+impl Hash for CvssV3 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized_vector().hash(state);
+    }
+}
 
-        // Environmental metrics
-        if let Some(cr) = &self.confidentiality_requirement {
-            write!(f, "/CR:{}", cr)?;
-        }
-        if let Some(ir) = &self.integrity_requirement {
-            write!(f, "/IR:{}", ir)?;
-        }
-        if let Some(ar) = &self.availability_requirement {
-            write!(f, "/AR:{}", ar)?;
-        }
-        if let Some(mav) = &self.modified_attack_vector {
-            write!(f, "/MAV:{}", mav)?;
-        }
-        if let Some(mac) = &self.modified_attack_complexity {
-            write!(f, "/MAC:{}", mac)?;
-        }
-        if let Some(mpr) = &self.modified_privileges_required {
-            write!(f, "/MPR:{}", mpr)?;
-        }
-        if let Some(mui) = &self.modified_user_interaction {
-            write!(f, "/MUI:{}", mui)?;
-        }
-        if let Some(ms) = &self.modified_scope {
-            write!(f, "/MS:{}", ms)?;
-        }
-        if let Some(mc) = &self.modified_confidentiality_impact {
-            write!(f, "/MC:{}", mc)?;
-        }
-        if let Some(mi) = &self.modified_integrity_impact {
-            write!(f, "/MI:{}", mi)?;
-        }
-        if let Some(ma) = &self.modified_availability_impact {
-            write!(f, "/MA:{}", ma)?;
+impl PartialOrd for CvssV3 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CvssV3 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let score = |c: &CvssV3| OrderedScore(c.calculated_base_score().unwrap_or(c.base_score));
+        score(self)
+            .cmp(&score(other))
+            .then_with(|| self.normalized_vector().cmp(&other.normalized_vector()))
+    }
+}
+
+/// Builds a [`CvssV3`] from its metrics, see [`CvssV3::builder`].
+#[derive(Clone, Debug)]
+pub struct CvssV3Builder {
+    cvss: CvssV3,
+}
+
+impl CvssV3 {
+    /// Returns a builder for constructing a `CvssV3` of the given version from its metrics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cvss_rs::v3::{
+    ///     AttackComplexity, AttackVector, CvssV3, Impact, PrivilegesRequired, Scope,
+    ///     UserInteraction,
+    /// };
+    /// use cvss_rs::version::VersionV3;
+    ///
+    /// let cvss = CvssV3::builder(VersionV3::V3_1)
+    ///     .attack_vector(AttackVector::Network)
+    ///     .attack_complexity(AttackComplexity::Low)
+    ///     .privileges_required(PrivilegesRequired::None)
+    ///     .user_interaction(UserInteraction::None)
+    ///     .scope(Scope::Unchanged)
+    ///     .confidentiality_impact(Impact::High)
+    ///     .integrity_impact(Impact::High)
+    ///     .availability_impact(Impact::High)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cvss.vector_string, "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H");
+    /// assert_eq!(cvss.base_score, 9.8);
+    /// ```
+    pub fn builder(version: VersionV3) -> CvssV3Builder {
+        CvssV3Builder {
+            cvss: CvssV3::empty(format!("CVSS:{version}"), version),
         }
+    }
+}
 
-        Ok(())
+impl CvssV3Builder {
+    /// Sets the attack vector metric (AV).
+    pub fn attack_vector(mut self, value: AttackVector) -> Self {
+        self.cvss.attack_vector = Some(value);
+        self
+    }
+
+    /// Sets the attack complexity metric (AC).
+    pub fn attack_complexity(mut self, value: AttackComplexity) -> Self {
+        self.cvss.attack_complexity = Some(value);
+        self
+    }
+
+    /// Sets the privileges required metric (PR).
+    pub fn privileges_required(mut self, value: PrivilegesRequired) -> Self {
+        self.cvss.privileges_required = Some(value);
+        self
+    }
+
+    /// Sets the user interaction metric (UI).
+    pub fn user_interaction(mut self, value: UserInteraction) -> Self {
+        self.cvss.user_interaction = Some(value);
+        self
+    }
+
+    /// Sets the scope metric (S).
+    pub fn scope(mut self, value: Scope) -> Self {
+        self.cvss.scope = Some(value);
+        self
+    }
+
+    /// Sets the confidentiality impact metric (C).
+    pub fn confidentiality_impact(mut self, value: Impact) -> Self {
+        self.cvss.confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the integrity impact metric (I).
+    pub fn integrity_impact(mut self, value: Impact) -> Self {
+        self.cvss.integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the availability impact metric (A).
+    pub fn availability_impact(mut self, value: Impact) -> Self {
+        self.cvss.availability_impact = Some(value);
+        self
+    }
+
+    /// Sets the exploit code maturity metric (E).
+    pub fn exploit_code_maturity(mut self, value: ExploitCodeMaturity) -> Self {
+        self.cvss.exploit_code_maturity = Some(value);
+        self
+    }
+
+    /// Sets the remediation level metric (RL).
+    pub fn remediation_level(mut self, value: RemediationLevel) -> Self {
+        self.cvss.remediation_level = Some(value);
+        self
+    }
+
+    /// Sets the report confidence metric (RC).
+    pub fn report_confidence(mut self, value: ReportConfidence) -> Self {
+        self.cvss.report_confidence = Some(value);
+        self
+    }
+
+    /// Sets the confidentiality requirement metric (CR).
+    pub fn confidentiality_requirement(mut self, value: SecurityRequirement) -> Self {
+        self.cvss.confidentiality_requirement = Some(value);
+        self
+    }
+
+    /// Sets the integrity requirement metric (IR).
+    pub fn integrity_requirement(mut self, value: SecurityRequirement) -> Self {
+        self.cvss.integrity_requirement = Some(value);
+        self
+    }
+
+    /// Sets the availability requirement metric (AR).
+    pub fn availability_requirement(mut self, value: SecurityRequirement) -> Self {
+        self.cvss.availability_requirement = Some(value);
+        self
+    }
+
+    /// Sets the modified attack vector metric (MAV).
+    pub fn modified_attack_vector(mut self, value: AttackVector) -> Self {
+        self.cvss.modified_attack_vector = Some(value);
+        self
+    }
+
+    /// Sets the modified attack complexity metric (MAC).
+    pub fn modified_attack_complexity(mut self, value: AttackComplexity) -> Self {
+        self.cvss.modified_attack_complexity = Some(value);
+        self
+    }
+
+    /// Sets the modified privileges required metric (MPR).
+    pub fn modified_privileges_required(mut self, value: PrivilegesRequired) -> Self {
+        self.cvss.modified_privileges_required = Some(value);
+        self
+    }
+
+    /// Sets the modified user interaction metric (MUI).
+    pub fn modified_user_interaction(mut self, value: UserInteraction) -> Self {
+        self.cvss.modified_user_interaction = Some(value);
+        self
+    }
+
+    /// Sets the modified scope metric (MS).
+    pub fn modified_scope(mut self, value: Scope) -> Self {
+        self.cvss.modified_scope = Some(value);
+        self
+    }
+
+    /// Sets the modified confidentiality impact metric (MC).
+    pub fn modified_confidentiality_impact(mut self, value: Impact) -> Self {
+        self.cvss.modified_confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified integrity impact metric (MI).
+    pub fn modified_integrity_impact(mut self, value: Impact) -> Self {
+        self.cvss.modified_integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified availability impact metric (MA).
+    pub fn modified_availability_impact(mut self, value: Impact) -> Self {
+        self.cvss.modified_availability_impact = Some(value);
+        self
+    }
+
+    /// Builds the `CvssV3`.
+    ///
+    /// Like [`CvssV3::from_metric_map`], the vector string, the base score and severity, and the
+    /// temporal and environmental scores and severities (if any such metrics are set) are
+    /// computed from the metrics.
+    ///
+    /// # Errors
+    ///
+    /// [`ParseError::MissingRequiredMetric`] naming the first missing base metric.
+    pub fn build(self) -> Result<CvssV3, ParseError> {
+        self.cvss.complete()
     }
 }
@@ -0,0 +1,54 @@
+//! Represents the CVSS v3.0 specification.
+//!
+//! CVSS v3.0 and v3.1 share their metrics and types, which are defined in [`crate::v3`] and
+//! re-exported here. The functions of this module only accept CVSS v3.0 vectors.
+//!
+//! # Example
+//!
+//! ```
+//! use cvss_rs::v3_0;
+//! use cvss_rs::version::VersionV3;
+//!
+//! let cvss: v3_0::Cvss = v3_0::parse("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+//!
+//! assert_eq!(cvss.version, Some(VersionV3::V3_0));
+//! assert!(v3_0::parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_err());
+//! ```
+
+#[cfg(feature = "std")]
+use alloc::string::String;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+pub use crate::v3::*;
+
+use crate::utils::prefix;
+use crate::{ParseError, Version};
+
+/// A CVSS v3.0 score object.
+pub type CvssV3_0 = CvssV3;
+
+/// A CVSS v3.0 score object, named for use as `v3_0::Cvss`.
+pub type Cvss = CvssV3_0;
+
+/// Parses a CVSS v3.0 vector string.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidPrefixVersion`] for vectors of any other version, in addition to
+/// the errors of [`CvssV3::from_str`].
+pub fn parse(vector: &str) -> Result<Cvss, ParseError> {
+    let (version, _) = prefix::extract_version_from_required_prefix(vector)?;
+    prefix::validate_allowed_prefix_version(&version, &[Version::V3_0])?;
+
+    CvssV3::from_str(vector)
+}
+
+/// Builds a CVSS v3.0 vector from a map of metric abbreviations to values.
+///
+/// See [`CvssV3::from_metric_map`].
+#[cfg(feature = "std")]
+pub fn from_metric_map(map: &HashMap<String, String>) -> Result<Cvss, ParseError> {
+    CvssV3::from_metric_map(Version::V3_0, map)
+}
@@ -0,0 +1,193 @@
+//! CVSS v4.0 lint checks for valid but unusual metric combinations.
+
+use super::*;
+use core::fmt;
+
+/// A metric combination that parses and scores fine, but is unusual enough to likely be a
+/// data-entry error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintWarning {
+    /// Neither the vulnerable nor the subsequent systems are impacted, so the score is always 0.0.
+    NoImpact,
+    /// Attack requirements are present (`AT:P`), although the attack complexity is low (`AC:L`)
+    /// and all impacts on the vulnerable system are high.
+    AttackRequirementsWithLowComplexity,
+    /// Safety (`S:P`) is present, but neither `MSI` nor `MSA` is set to Safety (`S`).
+    SafetyWithoutSafetyImpact,
+    /// A modified metric repeats the value of its base metric and has no effect on the score.
+    RedundantModifiedMetric { metric: &'static str },
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintWarning::NoImpact => write!(f, "no system is impacted, the score is always 0.0"),
+            LintWarning::AttackRequirementsWithLowComplexity => write!(
+                f,
+                "attack requirements are present, but the attack complexity is low and all \
+                 vulnerable system impacts are high"
+            ),
+            LintWarning::SafetyWithoutSafetyImpact => {
+                write!(f, "safety is present, but neither MSI nor MSA is S")
+            }
+            LintWarning::RedundantModifiedMetric { metric } => {
+                write!(f, "modified metric M{metric} repeats its base value")
+            }
+        }
+    }
+}
+
+pub(crate) fn lint(cvss: &CvssV4) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+
+    if let Some(metrics) = cvss.effective_metrics() {
+        let vuln_impacted = [
+            metrics.vuln_confidentiality_impact,
+            metrics.vuln_integrity_impact,
+            metrics.vuln_availability_impact,
+        ]
+        .iter()
+        .any(|i| *i != Impact::None);
+        let sub_impacted = [
+            metrics.sub_confidentiality_impact,
+            metrics.sub_integrity_impact,
+            metrics.sub_availability_impact,
+        ]
+        .iter()
+        .any(|i| *i != SubsequentImpact::None);
+
+        // A vulnerable system without impact is fine as long as a subsequent system is
+        // impacted, e.g. for reflected XSS
+        if !vuln_impacted && !sub_impacted {
+            warnings.push(LintWarning::NoImpact);
+        }
+
+        let full_impact = [
+            metrics.vuln_confidentiality_impact,
+            metrics.vuln_integrity_impact,
+            metrics.vuln_availability_impact,
+        ]
+        .iter()
+        .all(|i| *i == Impact::High);
+        if metrics.attack_requirements == AttackRequirements::Present
+            && metrics.attack_complexity == AttackComplexity::Low
+            && full_impact
+        {
+            warnings.push(LintWarning::AttackRequirementsWithLowComplexity);
+        }
+    }
+
+    let safety_impact = [
+        cvss.modified_sub_integrity_impact,
+        cvss.modified_sub_availability_impact,
+    ]
+    .contains(&Some(ModifiedSubsequentImpact::Safety));
+    if cvss.safety == Some(Safety::Present) && !safety_impact {
+        warnings.push(LintWarning::SafetyWithoutSafetyImpact);
+    }
+
+    // A modified metric is redundant when the vector also contains its base metric with the
+    // same value, e.g. `AV:N` and `MAV:N`
+    let redundant = [
+        (
+            "AV",
+            repeats(
+                cvss.modified_attack_vector
+                    .and_then(ModifiedAttackVector::value),
+                cvss.attack_vector,
+            ),
+        ),
+        (
+            "AC",
+            repeats(
+                cvss.modified_attack_complexity
+                    .and_then(ModifiedAttackComplexity::value),
+                cvss.attack_complexity,
+            ),
+        ),
+        (
+            "AT",
+            repeats(
+                cvss.modified_attack_requirements
+                    .and_then(ModifiedAttackRequirements::value),
+                cvss.attack_requirements,
+            ),
+        ),
+        (
+            "PR",
+            repeats(
+                cvss.modified_privileges_required
+                    .and_then(ModifiedPrivilegesRequired::value),
+                cvss.privileges_required,
+            ),
+        ),
+        (
+            "UI",
+            repeats(
+                cvss.modified_user_interaction
+                    .and_then(ModifiedUserInteraction::value),
+                cvss.user_interaction,
+            ),
+        ),
+        (
+            "VC",
+            repeats(
+                cvss.modified_vuln_confidentiality_impact
+                    .and_then(ModifiedImpact::value),
+                cvss.vuln_confidentiality_impact,
+            ),
+        ),
+        (
+            "VI",
+            repeats(
+                cvss.modified_vuln_integrity_impact
+                    .and_then(ModifiedImpact::value),
+                cvss.vuln_integrity_impact,
+            ),
+        ),
+        (
+            "VA",
+            repeats(
+                cvss.modified_vuln_availability_impact
+                    .and_then(ModifiedImpact::value),
+                cvss.vuln_availability_impact,
+            ),
+        ),
+        (
+            "SC",
+            repeats(
+                cvss.modified_sub_confidentiality_impact
+                    .and_then(ModifiedSubsequentImpact::value),
+                cvss.sub_confidentiality_impact,
+            ),
+        ),
+        (
+            "SI",
+            repeats(
+                cvss.modified_sub_integrity_impact
+                    .and_then(ModifiedSubsequentImpact::value),
+                cvss.sub_integrity_impact,
+            ),
+        ),
+        (
+            "SA",
+            repeats(
+                cvss.modified_sub_availability_impact
+                    .and_then(ModifiedSubsequentImpact::value),
+                cvss.sub_availability_impact,
+            ),
+        ),
+    ];
+    for (metric, repeated) in redundant {
+        if repeated {
+            warnings.push(LintWarning::RedundantModifiedMetric { metric });
+        }
+    }
+
+    warnings
+}
+
+/// Returns whether a defined modified metric has the same value as its base metric.
+fn repeats<T: PartialEq>(modified: Option<T>, base: Option<T>) -> bool {
+    modified.is_some() && modified == base
+}
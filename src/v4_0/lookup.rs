@@ -4,6 +4,8 @@
 //! <https://github.com/FIRSTdotorg/cvss-v4-calculator>
 
 use super::scoring::{MacroVector, VectorEq};
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// MacroVector to base score lookup table.
 ///
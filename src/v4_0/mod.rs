@@ -1,136 +1,175 @@
 //! Represents the CVSS v4.0 specification.
 
+mod lint;
 mod lookup;
 mod score;
 mod scoring;
 
-pub use score::Nomenclature;
+pub use lint::LintWarning;
+pub use score::{CvssV4Summary, EffectiveMetrics, Nomenclature, ScoreBreakdown};
+pub use scoring::MacroVector;
 
-use std::fmt;
-use std::str::FromStr;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-use crate::utils::{parse_metrics::parse_metric, prefix};
-use crate::{ParseError, Severity as UnifiedSeverity, Version};
+use crate::utils::parse_metrics::{
+    metric_pair, parse_metric, split_component, without_score_pseudo_metrics, ScorePseudoMetrics,
+};
+use crate::utils::prefix;
+#[cfg(feature = "serde")]
+use crate::utils::score_serde::deserialize_score;
+use crate::version::MetricGroup;
+use crate::{OrderedScore, ParseError, Severity as UnifiedSeverity, Version};
+
+/// Returns the score of a MacroVector in the CVSS v4.0 lookup table, before interpolation.
+///
+/// The MacroVector is given as its six EQ digits, e.g. `"000000"`. Returns None if the string
+/// is not a MacroVector or the MacroVector is not defined in the lookup table.
+///
+/// ```
+/// use cvss_rs::v4_0::base_value_for_macro;
+///
+/// assert_eq!(base_value_for_macro("000000"), Some(10.0));
+/// assert_eq!(base_value_for_macro("002000"), None);
+/// ```
+pub fn base_value_for_macro(mv: &str) -> Option<f64> {
+    lookup::lookup_global(&scoring::MacroVector::parse(mv)?)
+}
 
 /// Represents a CVSS v4.0 score object.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CvssV4 {
     /// The CVSS vector string.
+    ///
+    /// Scores are calculated from the metric fields, not from this string, so it may be empty,
+    /// e.g. for JSON objects that only contain the metrics.
+    #[cfg_attr(feature = "serde", serde(default))]
     pub vector_string: String,
     /// The base score, a value between 0.0 and 10.0.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_score"))]
     pub base_score: f64,
     /// The qualitative severity rating for the base score.
     pub base_severity: Severity,
 
     // --- Base Metrics ---
     /// Attack Vector (AV).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub attack_vector: Option<AttackVector>,
     /// Attack Complexity (AC).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub attack_complexity: Option<AttackComplexity>,
     /// Attack Requirements (AT).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub attack_requirements: Option<AttackRequirements>,
     /// Privileges Required (PR).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub privileges_required: Option<PrivilegesRequired>,
     /// User Interaction (UI).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub user_interaction: Option<UserInteraction>,
     /// Vulnerable System Confidentiality Impact (VC).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub vuln_confidentiality_impact: Option<Impact>,
     /// Vulnerable System Integrity Impact (VI).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub vuln_integrity_impact: Option<Impact>,
     /// Vulnerable System Availability Impact (VA).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub vuln_availability_impact: Option<Impact>,
     /// Subsequent System Confidentiality Impact (SC).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sub_confidentiality_impact: Option<SubsequentImpact>,
     /// Subsequent System Integrity Impact (SI).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sub_integrity_impact: Option<SubsequentImpact>,
     /// Subsequent System Availability Impact (SA).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sub_availability_impact: Option<SubsequentImpact>,
 
     // --- Threat Metrics ---
     /// Exploit Maturity (E).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub exploit_maturity: Option<ExploitMaturity>,
 
     // --- Environmental Metrics ---
     /// Confidentiality Requirement (CR).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub confidentiality_requirement: Option<Requirement>,
     /// Integrity Requirement (IR).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub integrity_requirement: Option<Requirement>,
     /// Availability Requirement (AR).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub availability_requirement: Option<Requirement>,
     /// Modified Attack Vector (MAV).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_attack_vector: Option<ModifiedAttackVector>,
     /// Modified Attack Complexity (MAC).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_attack_complexity: Option<ModifiedAttackComplexity>,
     /// Modified Attack Requirements (MAT).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_attack_requirements: Option<ModifiedAttackRequirements>,
     /// Modified Privileges Required (MPR).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_privileges_required: Option<ModifiedPrivilegesRequired>,
     /// Modified User Interaction (MUI).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_user_interaction: Option<ModifiedUserInteraction>,
     /// Modified Vulnerable System Confidentiality Impact (MVC).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_vuln_confidentiality_impact: Option<ModifiedImpact>,
     /// Modified Vulnerable System Integrity Impact (MVI).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_vuln_integrity_impact: Option<ModifiedImpact>,
     /// Modified Vulnerable System Availability Impact (MVA).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_vuln_availability_impact: Option<ModifiedImpact>,
     /// Modified Subsequent System Confidentiality Impact (MSC).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_sub_confidentiality_impact: Option<ModifiedSubsequentImpact>,
     /// Modified Subsequent System Integrity Impact (MSI).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_sub_integrity_impact: Option<ModifiedSubsequentImpact>,
     /// Modified Subsequent System Availability Impact (MSA).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub modified_sub_availability_impact: Option<ModifiedSubsequentImpact>,
 
     // --- Supplemental Metrics ---
-    #[serde(rename = "Safety")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "Safety"))]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub safety: Option<Safety>,
-    #[serde(rename = "Automatable")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "Automatable"))]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub automatable: Option<Automatable>,
-    #[serde(rename = "Recovery")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "Recovery"))]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub recovery: Option<Recovery>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub value_density: Option<ValueDensity>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub vulnerability_response_effort: Option<VulnerabilityResponseEffort>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub provider_urgency: Option<ProviderUrgency>,
 }
 
 /// Represents the qualitative severity rating of a vulnerability.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[strum(serialize_all = "UPPERCASE")]
 pub enum Severity {
     None,
     Low,
@@ -139,9 +178,23 @@ pub enum Severity {
     Critical,
 }
 
+impl Severity {
+    /// Returns the qualitative severity rating for a score per the CVSS v4.0 rating scale.
+    pub fn from_score(score: f64) -> Self {
+        match UnifiedSeverity::from_score(score) {
+            UnifiedSeverity::None => Severity::None,
+            UnifiedSeverity::Low => Severity::Low,
+            UnifiedSeverity::Medium => Severity::Medium,
+            UnifiedSeverity::High => Severity::High,
+            UnifiedSeverity::Critical => Severity::Critical,
+        }
+    }
+}
+
 /// Attack Vector (AV).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum AttackVector {
     #[strum(serialize = "N")]
     Network,
@@ -165,8 +218,9 @@ impl AttackVector {
 }
 
 /// Modified Attack Vector (MAV). Extends AttackVector with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum ModifiedAttackVector {
     #[strum(serialize = "N")]
     Network,
@@ -180,9 +234,23 @@ pub enum ModifiedAttackVector {
     NotDefined,
 }
 
+impl ModifiedAttackVector {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<AttackVector> {
+        match self {
+            ModifiedAttackVector::Network => Some(AttackVector::Network),
+            ModifiedAttackVector::Adjacent => Some(AttackVector::Adjacent),
+            ModifiedAttackVector::Local => Some(AttackVector::Local),
+            ModifiedAttackVector::Physical => Some(AttackVector::Physical),
+            ModifiedAttackVector::NotDefined => None,
+        }
+    }
+}
+
 /// Attack Complexity (AC).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum AttackComplexity {
     #[strum(serialize = "L")]
     Low,
@@ -200,8 +268,9 @@ impl AttackComplexity {
 }
 
 /// Modified Attack Complexity (MAC). Extends AttackComplexity with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum ModifiedAttackComplexity {
     #[strum(serialize = "L")]
     Low,
@@ -211,9 +280,21 @@ pub enum ModifiedAttackComplexity {
     NotDefined,
 }
 
+impl ModifiedAttackComplexity {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<AttackComplexity> {
+        match self {
+            ModifiedAttackComplexity::Low => Some(AttackComplexity::Low),
+            ModifiedAttackComplexity::High => Some(AttackComplexity::High),
+            ModifiedAttackComplexity::NotDefined => None,
+        }
+    }
+}
+
 /// Attack Requirements (AT).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum AttackRequirements {
     #[strum(serialize = "N")]
     None,
@@ -231,8 +312,9 @@ impl AttackRequirements {
 }
 
 /// Modified Attack Requirements (MAT). Extends AttackRequirements with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum ModifiedAttackRequirements {
     #[strum(serialize = "N")]
     None,
@@ -242,9 +324,21 @@ pub enum ModifiedAttackRequirements {
     NotDefined,
 }
 
+impl ModifiedAttackRequirements {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<AttackRequirements> {
+        match self {
+            ModifiedAttackRequirements::None => Some(AttackRequirements::None),
+            ModifiedAttackRequirements::Present => Some(AttackRequirements::Present),
+            ModifiedAttackRequirements::NotDefined => None,
+        }
+    }
+}
+
 /// Privileges Required (PR).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum PrivilegesRequired {
     #[strum(serialize = "N")]
     None,
@@ -265,8 +359,9 @@ impl PrivilegesRequired {
 }
 
 /// Modified Privileges Required (MPR). Extends PrivilegesRequired with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum ModifiedPrivilegesRequired {
     #[strum(serialize = "N")]
     None,
@@ -278,9 +373,22 @@ pub enum ModifiedPrivilegesRequired {
     NotDefined,
 }
 
+impl ModifiedPrivilegesRequired {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<PrivilegesRequired> {
+        match self {
+            ModifiedPrivilegesRequired::None => Some(PrivilegesRequired::None),
+            ModifiedPrivilegesRequired::Low => Some(PrivilegesRequired::Low),
+            ModifiedPrivilegesRequired::High => Some(PrivilegesRequired::High),
+            ModifiedPrivilegesRequired::NotDefined => None,
+        }
+    }
+}
+
 /// User Interaction (UI).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum UserInteraction {
     #[strum(serialize = "N")]
     None,
@@ -301,8 +409,9 @@ impl UserInteraction {
 }
 
 /// Modified User Interaction (MUI). Extends UserInteraction with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum ModifiedUserInteraction {
     #[strum(serialize = "N")]
     None,
@@ -314,9 +423,22 @@ pub enum ModifiedUserInteraction {
     NotDefined,
 }
 
+impl ModifiedUserInteraction {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<UserInteraction> {
+        match self {
+            ModifiedUserInteraction::None => Some(UserInteraction::None),
+            ModifiedUserInteraction::Passive => Some(UserInteraction::Passive),
+            ModifiedUserInteraction::Active => Some(UserInteraction::Active),
+            ModifiedUserInteraction::NotDefined => None,
+        }
+    }
+}
+
 /// Impact metrics for vulnerable system (VC, VI, VA).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum Impact {
     #[strum(serialize = "H")]
     High,
@@ -337,8 +459,9 @@ impl Impact {
 }
 
 /// Modified impact metrics for vulnerable system (MVC, MVI, MVA). Extends Impact with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum ModifiedImpact {
     #[strum(serialize = "H")]
     High,
@@ -350,10 +473,23 @@ pub enum ModifiedImpact {
     NotDefined,
 }
 
+impl ModifiedImpact {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<Impact> {
+        match self {
+            ModifiedImpact::High => Some(Impact::High),
+            ModifiedImpact::Low => Some(Impact::Low),
+            ModifiedImpact::None => Some(Impact::None),
+            ModifiedImpact::NotDefined => None,
+        }
+    }
+}
+
 /// Impact metrics for subsequent system (SC, SI, SA).
 /// Includes Safety variant which is unique to subsequent system impacts.
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum SubsequentImpact {
     #[strum(serialize = "S")]
     Safety,
@@ -377,8 +513,9 @@ impl SubsequentImpact {
 }
 
 /// Modified impact metrics for subsequent system (MSC, MSI, MSA). Extends SubsequentImpact with NotDefined (X).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum ModifiedSubsequentImpact {
     #[strum(serialize = "S")]
     Safety,
@@ -392,9 +529,23 @@ pub enum ModifiedSubsequentImpact {
     NotDefined,
 }
 
+impl ModifiedSubsequentImpact {
+    /// Returns the base metric value this replaces, or None if Not Defined.
+    pub(crate) fn value(self) -> Option<SubsequentImpact> {
+        match self {
+            ModifiedSubsequentImpact::Safety => Some(SubsequentImpact::Safety),
+            ModifiedSubsequentImpact::High => Some(SubsequentImpact::High),
+            ModifiedSubsequentImpact::Low => Some(SubsequentImpact::Low),
+            ModifiedSubsequentImpact::Negligible => Some(SubsequentImpact::None),
+            ModifiedSubsequentImpact::NotDefined => None,
+        }
+    }
+}
+
 /// Exploit Maturity (E).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum ExploitMaturity {
     #[strum(serialize = "A")]
     Attacked,
@@ -418,8 +569,9 @@ impl ExploitMaturity {
 }
 
 /// Requirement metrics (CR, IR, AR).
-#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Copy, Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
 pub enum Requirement {
     #[strum(serialize = "H")]
     High,
@@ -442,8 +594,9 @@ impl Requirement {
 }
 
 /// Safety (S).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Safety {
     #[strum(serialize = "N")]
     Negligible,
@@ -454,8 +607,9 @@ pub enum Safety {
 }
 
 /// Automatable (AU).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Automatable {
     #[strum(serialize = "N")]
     No,
@@ -466,8 +620,9 @@ pub enum Automatable {
 }
 
 /// Recovery (R).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Recovery {
     #[strum(serialize = "A")]
     Automatic,
@@ -480,8 +635,9 @@ pub enum Recovery {
 }
 
 /// Value Density (V).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum ValueDensity {
     #[strum(serialize = "D")]
     Diffuse,
@@ -492,8 +648,9 @@ pub enum ValueDensity {
 }
 
 /// Vulnerability Response Effort (RE).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum VulnerabilityResponseEffort {
     #[strum(serialize = "L")]
     Low,
@@ -506,8 +663,9 @@ pub enum VulnerabilityResponseEffort {
 }
 
 /// Provider Urgency (U).
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, PartialEq, EnumString, Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 #[strum(ascii_case_insensitive)]
 pub enum ProviderUrgency {
     #[strum(serialize = "Clear")]
@@ -527,6 +685,116 @@ impl CvssV4 {
         &self.vector_string
     }
 
+    /// Consumes the object and returns its vector string without cloning it.
+    pub fn into_vector_string(self) -> String {
+        self.vector_string
+    }
+
+    /// Returns the metrics that are set, as `(abbreviation, value)` pairs in specification
+    /// order, e.g. `("AV", "N")`.
+    pub fn metrics(&self) -> Vec<(&'static str, String)> {
+        [
+            metric_pair("AV", &self.attack_vector),
+            metric_pair("AC", &self.attack_complexity),
+            metric_pair("AT", &self.attack_requirements),
+            metric_pair("PR", &self.privileges_required),
+            metric_pair("UI", &self.user_interaction),
+            metric_pair("VC", &self.vuln_confidentiality_impact),
+            metric_pair("VI", &self.vuln_integrity_impact),
+            metric_pair("VA", &self.vuln_availability_impact),
+            metric_pair("SC", &self.sub_confidentiality_impact),
+            metric_pair("SI", &self.sub_integrity_impact),
+            metric_pair("SA", &self.sub_availability_impact),
+            metric_pair("E", &self.exploit_maturity),
+            metric_pair("CR", &self.confidentiality_requirement),
+            metric_pair("IR", &self.integrity_requirement),
+            metric_pair("AR", &self.availability_requirement),
+            metric_pair("MAV", &self.modified_attack_vector),
+            metric_pair("MAC", &self.modified_attack_complexity),
+            metric_pair("MAT", &self.modified_attack_requirements),
+            metric_pair("MPR", &self.modified_privileges_required),
+            metric_pair("MUI", &self.modified_user_interaction),
+            metric_pair("MVC", &self.modified_vuln_confidentiality_impact),
+            metric_pair("MVI", &self.modified_vuln_integrity_impact),
+            metric_pair("MVA", &self.modified_vuln_availability_impact),
+            metric_pair("MSC", &self.modified_sub_confidentiality_impact),
+            metric_pair("MSI", &self.modified_sub_integrity_impact),
+            metric_pair("MSA", &self.modified_sub_availability_impact),
+            metric_pair("S", &self.safety),
+            metric_pair("AU", &self.automatable),
+            metric_pair("R", &self.recovery),
+            metric_pair("V", &self.value_density),
+            metric_pair("RE", &self.vulnerability_response_effort),
+            metric_pair("U", &self.provider_urgency),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Builds the vector string from the metric fields, e.g.
+    /// `CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N`.
+    ///
+    /// Unlike the stored [`CvssV4::vector_string`], this reflects changes to the fields. Only
+    /// present metrics are written, in specification order, and Not Defined (`X`) metrics are
+    /// omitted like in the FIRST calculator; use [`CvssV4::to_vector_string_verbose`] to keep
+    /// them.
+    pub fn to_vector_string(&self) -> String {
+        self.format_vector(true)
+    }
+
+    /// Builds the vector string from the metric fields like [`CvssV4::to_vector_string`], but
+    /// keeps Not Defined (`X`) metrics.
+    pub fn to_vector_string_verbose(&self) -> String {
+        self.format_vector(false)
+    }
+
+    /// Builds the canonical form of the vector string, e.g. for deduplication or for comparison
+    /// against other tools.
+    ///
+    /// This is the same as [`CvssV4::to_vector_string`]: metrics are written in specification
+    /// order (base, threat, environmental, then supplemental metrics) with canonical casing, and
+    /// Not Defined (`X`) metrics are omitted.
+    pub fn normalized_vector(&self) -> String {
+        self.to_vector_string()
+    }
+
+    fn format_vector(&self, skip_not_defined: bool) -> String {
+        let mut vector = "CVSS:4.0".to_string();
+        for (key, value) in self.metrics() {
+            if !(skip_not_defined && value == "X") {
+                vector.push_str(&format!("/{key}:{value}"));
+            }
+        }
+        vector
+    }
+
+    /// Builds a vector string with every base, threat and environmental metric written
+    /// explicitly, using `X` (Not Defined) for absent ones, e.g.
+    /// `CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X/...`.
+    ///
+    /// This is the opposite of [`CvssV4::normalized_vector`] and is useful for comparing
+    /// against tools that always emit all metrics. Base metrics have no Not Defined value, so
+    /// missing ones are skipped, like supplemental metrics, which do not affect the score and are
+    /// only written if present.
+    pub fn to_explicit_string(&self) -> String {
+        let metrics = self.metrics();
+        let mut vector = "CVSS:4.0".to_string();
+        for key in Version::V4.metrics() {
+            let value = metrics
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.as_str());
+            let value = match (value, Version::V4.metric_group(key)) {
+                (Some(value), _) => value,
+                (None, Some(MetricGroup::Threat | MetricGroup::Environmental)) => "X",
+                (None, _) => continue,
+            };
+            vector.push_str(&format!("/{key}:{value}"));
+        }
+        vector
+    }
+
     pub fn base_score(&self) -> f64 {
         self.base_score
     }
@@ -541,65 +809,184 @@ impl CvssV4 {
         })
     }
 
-    /// Calculates the base score from the base metrics.
-    /// Returns None if required base metrics are missing.
-    ///
-    /// This uses the CVSS v4.0 MacroVector-based scoring algorithm as specified in:
-    /// <https://www.first.org/cvss/v4.0/specification-document>
-    ///
-    /// The score is rounded to one decimal place as required by the specification.
-    ///
-    /// Note: In CVSS v4.0, the "base score" excludes threat metrics (like E) for
-    /// backwards compatibility with the CVSS v3.x schema, even though CVSS v4.0
-    /// conceptually has a unified score. Use `calculated_full_score()` if you need
-    /// the full score including threat metrics.
-    pub fn calculated_base_score(&self) -> Option<f64> {
-        let score = scoring::calculate_base_score(self)?;
-        Some(score::round_v4(score))
-    }
+    /// Column names of [`CvssV4::feature_vector`], in order.
+    pub const FEATURE_COLUMNS: [&'static str; 26] = [
+        "AV", "AC", "AT", "PR", "UI", "VC", "VI", "VA", "SC", "SI", "SA", "E", "CR", "IR", "AR",
+        "MAV", "MAC", "MAT", "MPR", "MUI", "MVC", "MVI", "MVA", "MSC", "MSI", "MSA",
+    ];
 
-    /// Calculates the full CVSS v4.0 score including threat metrics (E).
-    /// Returns None if required base metrics are missing.
+    /// Encodes the metrics as a fixed-size numeric feature vector, e.g. as input for a model.
     ///
-    /// This includes the Exploit Maturity (E) metric in the score calculation.
-    /// For base score only (excluding E), use `calculated_base_score()`.
-    pub fn calculated_full_score(&self) -> Option<f64> {
-        let score = scoring::calculate_score(self)?;
-        Some(score::round_v4(score))
-    }
+    /// Each column holds `1.0 - level` of the metric, where `level()` is its severity level as
+    /// used by the scoring algorithm, in the order given by [`CvssV4::FEATURE_COLUMNS`]. Present
+    /// metrics are therefore encoded between `0.7` and `1.0`, with `1.0` for the most severe
+    /// value, and absent metrics as `0.0`. Modified base metrics set to Not Defined (`X`) are
+    /// encoded like absent ones. Supplemental metrics are not part of the feature vector.
+    pub fn feature_vector(&self) -> Vec<f64> {
+        fn feature<T>(metric: &Option<T>, level: impl Fn(&T) -> f64) -> f64 {
+            metric.as_ref().map_or(0.0, |value| 1.0 - level(value))
+        }
+        fn modified<M: Copy, T>(
+            metric: &Option<M>,
+            value: impl Fn(M) -> Option<T>,
+            level: impl Fn(&T) -> f64,
+        ) -> f64 {
+            metric
+                .and_then(value)
+                .map_or(0.0, |value| 1.0 - level(&value))
+        }
 
-    /// Calculates the CVSS v4.0 score and returns it along with the appropriate nomenclature.
-    ///
-    /// Returns a tuple of (score, nomenclature) where:
-    /// - score: The calculated CVSS v4.0 score (0.0-10.0), rounded to one decimal place
-    /// - nomenclature: The appropriate nomenclature based on which metrics are present:
-    ///   - CVSS-B: Base metrics only
-    ///   - CVSS-BT: Base + Threat metrics
-    ///   - CVSS-BE: Base + Environmental metrics
-    ///   - CVSS-BTE: Base + Threat + Environmental metrics
-    ///
-    /// Returns None if required base metrics are missing.
-    pub fn calculated_score(&self) -> Option<(f64, Nomenclature)> {
-        let score = scoring::calculate_score(self)?;
-        let rounded_score = score::round_v4(score);
-        let nomenclature = Nomenclature::from(self);
-        Some((rounded_score, nomenclature))
+        vec![
+            feature(&self.attack_vector, AttackVector::level),
+            feature(&self.attack_complexity, AttackComplexity::level),
+            feature(&self.attack_requirements, AttackRequirements::level),
+            feature(&self.privileges_required, PrivilegesRequired::level),
+            feature(&self.user_interaction, UserInteraction::level),
+            feature(&self.vuln_confidentiality_impact, Impact::level),
+            feature(&self.vuln_integrity_impact, Impact::level),
+            feature(&self.vuln_availability_impact, Impact::level),
+            feature(&self.sub_confidentiality_impact, SubsequentImpact::level),
+            feature(&self.sub_integrity_impact, SubsequentImpact::level),
+            feature(&self.sub_availability_impact, SubsequentImpact::level),
+            feature(&self.exploit_maturity, ExploitMaturity::level),
+            feature(&self.confidentiality_requirement, Requirement::level),
+            feature(&self.integrity_requirement, Requirement::level),
+            feature(&self.availability_requirement, Requirement::level),
+            modified(
+                &self.modified_attack_vector,
+                ModifiedAttackVector::value,
+                AttackVector::level,
+            ),
+            modified(
+                &self.modified_attack_complexity,
+                ModifiedAttackComplexity::value,
+                AttackComplexity::level,
+            ),
+            modified(
+                &self.modified_attack_requirements,
+                ModifiedAttackRequirements::value,
+                AttackRequirements::level,
+            ),
+            modified(
+                &self.modified_privileges_required,
+                ModifiedPrivilegesRequired::value,
+                PrivilegesRequired::level,
+            ),
+            modified(
+                &self.modified_user_interaction,
+                ModifiedUserInteraction::value,
+                UserInteraction::level,
+            ),
+            modified(
+                &self.modified_vuln_confidentiality_impact,
+                ModifiedImpact::value,
+                Impact::level,
+            ),
+            modified(
+                &self.modified_vuln_integrity_impact,
+                ModifiedImpact::value,
+                Impact::level,
+            ),
+            modified(
+                &self.modified_vuln_availability_impact,
+                ModifiedImpact::value,
+                Impact::level,
+            ),
+            modified(
+                &self.modified_sub_confidentiality_impact,
+                ModifiedSubsequentImpact::value,
+                SubsequentImpact::level,
+            ),
+            modified(
+                &self.modified_sub_integrity_impact,
+                ModifiedSubsequentImpact::value,
+                SubsequentImpact::level,
+            ),
+            modified(
+                &self.modified_sub_availability_impact,
+                ModifiedSubsequentImpact::value,
+                SubsequentImpact::level,
+            ),
+        ]
     }
-}
-
-impl FromStr for CvssV4 {
-    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Extract and validate version prefix
-        let (version, components_str) = prefix::extract_version_from_required_prefix(s)?;
+    /// Returns the severity distance of each present base metric from its least severe value,
+    /// relative to the full range of the metric, in specification order.
+    ///
+    /// A value of 1.0 means the metric takes its most severe value.
+    pub(crate) fn base_contributions(&self) -> Vec<(&'static str, f64)> {
+        fn distance(level: f64, least_severe: f64) -> f64 {
+            1.0 - level / least_severe
+        }
+        let impact_min = Impact::None.level();
+        let sub_impact_min = SubsequentImpact::None.level();
 
-        // Must be 4.0
-        prefix::validate_allowed_prefix_version(&version, &[Version::V4])?;
+        [
+            (
+                "AV",
+                self.attack_vector
+                    .map(|av| distance(av.level(), AttackVector::Physical.level())),
+            ),
+            (
+                "AC",
+                self.attack_complexity
+                    .map(|ac| distance(ac.level(), AttackComplexity::High.level())),
+            ),
+            (
+                "AT",
+                self.attack_requirements
+                    .map(|at| distance(at.level(), AttackRequirements::Present.level())),
+            ),
+            (
+                "PR",
+                self.privileges_required
+                    .map(|pr| distance(pr.level(), PrivilegesRequired::High.level())),
+            ),
+            (
+                "UI",
+                self.user_interaction
+                    .map(|ui| distance(ui.level(), UserInteraction::Active.level())),
+            ),
+            (
+                "VC",
+                self.vuln_confidentiality_impact
+                    .map(|vc| distance(vc.level(), impact_min)),
+            ),
+            (
+                "VI",
+                self.vuln_integrity_impact
+                    .map(|vi| distance(vi.level(), impact_min)),
+            ),
+            (
+                "VA",
+                self.vuln_availability_impact
+                    .map(|va| distance(va.level(), impact_min)),
+            ),
+            (
+                "SC",
+                self.sub_confidentiality_impact
+                    .map(|sc| distance(sc.level(), sub_impact_min)),
+            ),
+            (
+                "SI",
+                self.sub_integrity_impact
+                    .map(|si| distance(si.level(), sub_impact_min)),
+            ),
+            (
+                "SA",
+                self.sub_availability_impact
+                    .map(|sa| distance(sa.level(), sub_impact_min)),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(metric, contribution)| Some((metric, contribution?)))
+        .collect()
+    }
 
-        // Initialize a CvssV4 with empty fields
-        let mut cvss = CvssV4 {
-            vector_string: s.to_string(),
+    /// Creates a `CvssV4` without any metrics or scores.
+    pub(crate) fn empty(vector_string: String) -> Self {
+        CvssV4 {
+            vector_string,
             base_score: 0.0,
             base_severity: Severity::None,
             attack_vector: None,
@@ -634,193 +1021,694 @@ impl FromStr for CvssV4 {
             value_density: None,
             vulnerability_response_effort: None,
             provider_urgency: None,
-        };
+        }
+    }
 
-        // Parse metrics
-        for component in components_str.split('/') {
-            if component.is_empty() {
-                continue;
-            }
+    /// Like [`CvssV4::set_metric`], but also accepts the `BASESCORE` and `BASESEVERITY`
+    /// pseudo-metrics, which are collected in `scores`.
+    pub(crate) fn set_component(
+        &mut self,
+        scores: &mut ScorePseudoMetrics<Severity>,
+        key: &str,
+        value: &str,
+    ) -> Result<(), ParseError> {
+        // v4.0 has no temporal score, so TEMPORALSCORE is rejected as an unknown metric
+        if key == "TEMPORALSCORE" || !scores.parse(key, value)? {
+            self.set_metric(key, value)?;
+        }
+        Ok(())
+    }
 
-            let mut parts = component.split(':');
-            let key = parts
-                .next()
-                .ok_or_else(|| ParseError::InvalidComponent {
-                    component: component.to_string(),
-                })?
-                .to_ascii_uppercase();
-            let value = parts
-                .next()
-                .ok_or_else(|| ParseError::InvalidComponent {
-                    component: component.to_string(),
-                })?
-                .to_ascii_uppercase();
-
-            // Check for extra colons
-            if parts.next().is_some() {
-                return Err(ParseError::InvalidComponent {
-                    component: component.to_string(),
+    /// Populates the stored score fields from the score pseudo-metrics. Without an explicit
+    /// `baseSeverity`, the base severity is derived from `baseScore`.
+    pub(crate) fn set_scores(&mut self, scores: ScorePseudoMetrics<Severity>) {
+        if let Some(score) = scores.base_score {
+            self.base_score = score;
+            self.base_severity = Severity::from_score(score);
+        }
+        if let Some(severity) = scores.base_severity {
+            self.base_severity = severity;
+        }
+    }
+
+    /// Parses `value` and stores it in the metric field identified by the abbreviation `key`.
+    /// Both are expected in uppercase.
+    pub(crate) fn set_metric(&mut self, key: &str, value: &str) -> Result<(), ParseError> {
+        match key {
+            // Base metrics
+            "AV" => parse_metric(&mut self.attack_vector, value, key)?,
+            "AC" => parse_metric(&mut self.attack_complexity, value, key)?,
+            "AT" => parse_metric(&mut self.attack_requirements, value, key)?,
+            "PR" => parse_metric(&mut self.privileges_required, value, key)?,
+            "UI" => parse_metric(&mut self.user_interaction, value, key)?,
+            "VC" => parse_metric(&mut self.vuln_confidentiality_impact, value, key)?,
+            "VI" => parse_metric(&mut self.vuln_integrity_impact, value, key)?,
+            "VA" => parse_metric(&mut self.vuln_availability_impact, value, key)?,
+            "SC" => parse_metric(&mut self.sub_confidentiality_impact, value, key)?,
+            "SI" => parse_metric(&mut self.sub_integrity_impact, value, key)?,
+            "SA" => parse_metric(&mut self.sub_availability_impact, value, key)?,
+            // Threat metrics
+            "E" => parse_metric(&mut self.exploit_maturity, value, key)?,
+            // Environmental metrics
+            "CR" => parse_metric(&mut self.confidentiality_requirement, value, key)?,
+            "IR" => parse_metric(&mut self.integrity_requirement, value, key)?,
+            "AR" => parse_metric(&mut self.availability_requirement, value, key)?,
+            // Modified base metrics
+            "MAV" => parse_metric(&mut self.modified_attack_vector, value, key)?,
+            "MAC" => parse_metric(&mut self.modified_attack_complexity, value, key)?,
+            "MAT" => parse_metric(&mut self.modified_attack_requirements, value, key)?,
+            "MPR" => parse_metric(&mut self.modified_privileges_required, value, key)?,
+            "MUI" => parse_metric(&mut self.modified_user_interaction, value, key)?,
+            "MVC" => parse_metric(&mut self.modified_vuln_confidentiality_impact, value, key)?,
+            "MVI" => parse_metric(&mut self.modified_vuln_integrity_impact, value, key)?,
+            "MVA" => parse_metric(&mut self.modified_vuln_availability_impact, value, key)?,
+            "MSC" => parse_metric(&mut self.modified_sub_confidentiality_impact, value, key)?,
+            "MSI" => parse_metric(&mut self.modified_sub_integrity_impact, value, key)?,
+            "MSA" => parse_metric(&mut self.modified_sub_availability_impact, value, key)?,
+            // Supplemental metrics
+            "S" => parse_metric(&mut self.safety, value, key)?,
+            "AU" => parse_metric(&mut self.automatable, value, key)?,
+            "R" => parse_metric(&mut self.recovery, value, key)?,
+            "V" => parse_metric(&mut self.value_density, value, key)?,
+            "RE" => parse_metric(&mut self.vulnerability_response_effort, value, key)?,
+            "U" => parse_metric(&mut self.provider_urgency, value, key)?,
+            _ => {
+                return Err(ParseError::UnknownMetric {
+                    metric: key.to_string(),
+                    position: None,
                 });
             }
+        }
+        Ok(())
+    }
+
+    /// Parses a CVSS v4.0 vector string like [`CvssV4::from_str`], but without requiring the
+    /// base metrics, e.g. `CVSS:4.0/AV:N/AC:L`.
+    ///
+    /// Like for the other versions, this also accepts a vector without any metrics, e.g.
+    /// `CVSS:4.0/`. Partial vectors cannot be scored, so the `calculated_*` methods return None
+    /// for them.
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseError> {
+        // Extract and validate version prefix
+        let (version, components_str) = prefix::extract_version_from_required_prefix(s)?;
+
+        // Must be 4.0
+        prefix::validate_allowed_prefix_version(&version, &[Version::V4])?;
+
+        let mut cvss = CvssV4::empty(without_score_pseudo_metrics(s));
 
-            match key.as_str() {
-                // Base metrics
-                "AV" => parse_metric(&mut cvss.attack_vector, &value, &key)?,
-                "AC" => parse_metric(&mut cvss.attack_complexity, &value, &key)?,
-                "AT" => parse_metric(&mut cvss.attack_requirements, &value, &key)?,
-                "PR" => parse_metric(&mut cvss.privileges_required, &value, &key)?,
-                "UI" => parse_metric(&mut cvss.user_interaction, &value, &key)?,
-                "VC" => parse_metric(&mut cvss.vuln_confidentiality_impact, &value, &key)?,
-                "VI" => parse_metric(&mut cvss.vuln_integrity_impact, &value, &key)?,
-                "VA" => parse_metric(&mut cvss.vuln_availability_impact, &value, &key)?,
-                "SC" => parse_metric(&mut cvss.sub_confidentiality_impact, &value, &key)?,
-                "SI" => parse_metric(&mut cvss.sub_integrity_impact, &value, &key)?,
-                "SA" => parse_metric(&mut cvss.sub_availability_impact, &value, &key)?,
-                // Threat metrics
-                "E" => parse_metric(&mut cvss.exploit_maturity, &value, &key)?,
-                // Environmental metrics
-                "CR" => parse_metric(&mut cvss.confidentiality_requirement, &value, &key)?,
-                "IR" => parse_metric(&mut cvss.integrity_requirement, &value, &key)?,
-                "AR" => parse_metric(&mut cvss.availability_requirement, &value, &key)?,
-                // Modified base metrics
-                "MAV" => parse_metric(&mut cvss.modified_attack_vector, &value, &key)?,
-                "MAC" => parse_metric(&mut cvss.modified_attack_complexity, &value, &key)?,
-                "MAT" => parse_metric(&mut cvss.modified_attack_requirements, &value, &key)?,
-                "MPR" => parse_metric(&mut cvss.modified_privileges_required, &value, &key)?,
-                "MUI" => parse_metric(&mut cvss.modified_user_interaction, &value, &key)?,
-                "MVC" => {
-                    parse_metric(&mut cvss.modified_vuln_confidentiality_impact, &value, &key)?
-                }
-                "MVI" => parse_metric(&mut cvss.modified_vuln_integrity_impact, &value, &key)?,
-                "MVA" => parse_metric(&mut cvss.modified_vuln_availability_impact, &value, &key)?,
-                "MSC" => parse_metric(&mut cvss.modified_sub_confidentiality_impact, &value, &key)?,
-                "MSI" => parse_metric(&mut cvss.modified_sub_integrity_impact, &value, &key)?,
-                "MSA" => parse_metric(&mut cvss.modified_sub_availability_impact, &value, &key)?,
-                // Supplemental metrics
-                "S" => parse_metric(&mut cvss.safety, &value, &key)?,
-                "AU" => parse_metric(&mut cvss.automatable, &value, &key)?,
-                "R" => parse_metric(&mut cvss.recovery, &value, &key)?,
-                "V" => parse_metric(&mut cvss.value_density, &value, &key)?,
-                "RE" => parse_metric(&mut cvss.vulnerability_response_effort, &value, &key)?,
-                "U" => parse_metric(&mut cvss.provider_urgency, &value, &key)?,
-                _ => {
-                    return Err(ParseError::UnknownMetric { metric: key });
-                }
+        // Parse metrics, tracking the byte offset of each component for error reporting
+        let mut scores = ScorePseudoMetrics::new();
+        let mut offset = s.len() - components_str.len();
+        for component in components_str.split('/') {
+            let position = offset;
+            offset += component.len() + 1;
+            if component.is_empty() {
+                continue;
             }
+
+            split_component(component)
+                .and_then(|(key, value)| cvss.set_component(&mut scores, &key, &value))
+                .map_err(|err| err.at(position))?;
         }
+        cvss.set_scores(scores);
 
         Ok(cvss)
     }
-}
 
-impl fmt::Display for CvssV4 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "CVSS:4.0")?;
+    /// Returns whether all eleven base metrics are present, so that the base score can be
+    /// calculated.
+    pub fn is_complete(&self) -> bool {
+        self.missing_base_metric().is_none()
+    }
 
-        // Base metrics
-        if let Some(av) = &self.attack_vector {
-            write!(f, "/AV:{}", av)?;
-        }
-        if let Some(ac) = &self.attack_complexity {
-            write!(f, "/AC:{}", ac)?;
-        }
-        if let Some(at) = &self.attack_requirements {
-            write!(f, "/AT:{}", at)?;
-        }
-        if let Some(pr) = &self.privileges_required {
-            write!(f, "/PR:{}", pr)?;
-        }
-        if let Some(ui) = &self.user_interaction {
-            write!(f, "/UI:{}", ui)?;
-        }
-        if let Some(vc) = &self.vuln_confidentiality_impact {
-            write!(f, "/VC:{}", vc)?;
-        }
-        if let Some(vi) = &self.vuln_integrity_impact {
-            write!(f, "/VI:{}", vi)?;
-        }
-        if let Some(va) = &self.vuln_availability_impact {
-            write!(f, "/VA:{}", va)?;
-        }
-        if let Some(sc) = &self.sub_confidentiality_impact {
-            write!(f, "/SC:{}", sc)?;
-        }
-        if let Some(si) = &self.sub_integrity_impact {
-            write!(f, "/SI:{}", si)?;
-        }
-        if let Some(sa) = &self.sub_availability_impact {
-            write!(f, "/SA:{}", sa)?;
-        }
+    /// Returns the abbreviation of the first missing base metric, in specification order.
+    fn missing_base_metric(&self) -> Option<&'static str> {
+        [
+            ("AV", self.attack_vector.is_none()),
+            ("AC", self.attack_complexity.is_none()),
+            ("AT", self.attack_requirements.is_none()),
+            ("PR", self.privileges_required.is_none()),
+            ("UI", self.user_interaction.is_none()),
+            ("VC", self.vuln_confidentiality_impact.is_none()),
+            ("VI", self.vuln_integrity_impact.is_none()),
+            ("VA", self.vuln_availability_impact.is_none()),
+            ("SC", self.sub_confidentiality_impact.is_none()),
+            ("SI", self.sub_integrity_impact.is_none()),
+            ("SA", self.sub_availability_impact.is_none()),
+        ]
+        .into_iter()
+        .find_map(|(metric, missing)| missing.then_some(metric))
+    }
 
-        // Threat metrics
-        if let Some(e) = &self.exploit_maturity {
-            write!(f, "/E:{}", e)?;
-        }
+    /// Calculates the base score from the base metrics.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// This uses the CVSS v4.0 MacroVector-based scoring algorithm as specified in:
+    /// <https://www.first.org/cvss/v4.0/specification-document>
+    ///
+    /// The score is rounded to one decimal place as required by the specification.
+    ///
+    /// Note: In CVSS v4.0, the "base score" excludes threat metrics (like E) for
+    /// backwards compatibility with the CVSS v3.x schema, even though CVSS v4.0
+    /// conceptually has a unified score. Use `calculated_full_score()` if you need
+    /// the full score including threat metrics.
+    ///
+    /// Environmental metrics are applied, so this is the CVSS-BE score if any are present, and
+    /// the CVSS-B score otherwise. See [`CvssV4::calculated_score_for`] to calculate the score
+    /// of a given nomenclature.
+    pub fn calculated_base_score(&self) -> Option<f64> {
+        let score = scoring::calculate_base_score(self)?;
+        Some(score::round_v4(score))
+    }
 
-        // Environmental metrics
-        if let Some(cr) = &self.confidentiality_requirement {
-            write!(f, "/CR:{}", cr)?;
-        }
-        if let Some(ir) = &self.integrity_requirement {
-            write!(f, "/IR:{}", ir)?;
-        }
-        if let Some(ar) = &self.availability_requirement {
-            write!(f, "/AR:{}", ar)?;
-        }
-        if let Some(mav) = &self.modified_attack_vector {
-            write!(f, "/MAV:{}", mav)?;
-        }
-        if let Some(mac) = &self.modified_attack_complexity {
-            write!(f, "/MAC:{}", mac)?;
-        }
-        if let Some(mat) = &self.modified_attack_requirements {
-            write!(f, "/MAT:{}", mat)?;
-        }
-        if let Some(mpr) = &self.modified_privileges_required {
-            write!(f, "/MPR:{}", mpr)?;
+    /// Returns the qualitative severity rating of `calculated_base_score()`.
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_base_severity(&self) -> Option<Severity> {
+        self.calculated_base_score().map(Severity::from_score)
+    }
+
+    /// Calculates the full CVSS v4.0 score including threat metrics (E), i.e. the CVSS-BTE
+    /// score if environmental metrics are present.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// This includes the Exploit Maturity (E) metric in the score calculation.
+    /// For base score only (excluding E), use `calculated_base_score()`.
+    pub fn calculated_full_score(&self) -> Option<f64> {
+        let score = scoring::calculate_score(self)?;
+        Some(score::round_v4(score))
+    }
+
+    /// Calculates the highest score this vector can be rated with, holding the base metrics fixed.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// The threat and security requirement metrics are set to their most severe values (`E:A`,
+    /// `CR:H`, `IR:H`, `AR:H`), and the modified base metrics are ignored, as they would replace
+    /// the base metrics.
+    pub fn max_achievable_score(&self) -> Option<f64> {
+        let worst_case = CvssV4 {
+            exploit_maturity: Some(ExploitMaturity::Attacked),
+            ..self.without_environmental()
+        };
+        worst_case.calculated_full_score()
+    }
+
+    /// Calculates the CVSS-BT score from the base and threat metrics (E).
+    /// Returns None if required base metrics are missing.
+    ///
+    /// Environmental metrics are ignored. For the score including them, use
+    /// `calculated_full_score()`.
+    pub fn calculated_threat_score(&self) -> Option<f64> {
+        self.without_environmental().calculated_full_score()
+    }
+
+    /// Calculates the score labeled with `nomenclature`, e.g. the one detected with
+    /// `Nomenclature::from(&cvss)`.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// The metrics not covered by the nomenclature are ignored:
+    ///
+    /// - CVSS-B: base metrics only, the score of a copy without threat and environmental metrics
+    /// - CVSS-BE: [`CvssV4::calculated_base_score`]
+    /// - CVSS-BT: [`CvssV4::calculated_threat_score`]
+    /// - CVSS-BTE: [`CvssV4::calculated_full_score`]
+    pub fn calculated_score_for(&self, nomenclature: &Nomenclature) -> Option<f64> {
+        match nomenclature {
+            Nomenclature::CvssB => self.without_environmental().calculated_base_score(),
+            Nomenclature::CvssBE => self.calculated_base_score(),
+            Nomenclature::CvssBT => self.calculated_threat_score(),
+            Nomenclature::CvssBTE => self.calculated_full_score(),
         }
-        if let Some(mui) = &self.modified_user_interaction {
-            write!(f, "/MUI:{}", mui)?;
+    }
+
+    /// Returns a copy without environmental metrics, so that the security requirements take
+    /// their default (High) and the base metrics are not modified.
+    fn without_environmental(&self) -> CvssV4 {
+        CvssV4 {
+            confidentiality_requirement: None,
+            integrity_requirement: None,
+            availability_requirement: None,
+            modified_attack_vector: None,
+            modified_attack_complexity: None,
+            modified_attack_requirements: None,
+            modified_privileges_required: None,
+            modified_user_interaction: None,
+            modified_vuln_confidentiality_impact: None,
+            modified_vuln_integrity_impact: None,
+            modified_vuln_availability_impact: None,
+            modified_sub_confidentiality_impact: None,
+            modified_sub_integrity_impact: None,
+            modified_sub_availability_impact: None,
+            ..self.clone()
         }
-        if let Some(mvc) = &self.modified_vuln_confidentiality_impact {
-            write!(f, "/MVC:{}", mvc)?;
+    }
+
+    /// Returns the metric values used for scoring, after merging the modified (environmental)
+    /// metrics into the base metrics. See [`EffectiveMetrics`] for the defaults that apply.
+    ///
+    /// This explains why an environmental score differs from the base score.
+    /// Returns None if required base metrics are missing.
+    pub fn effective_metrics(&self) -> Option<EffectiveMetrics> {
+        scoring::effective_metrics(self)
+    }
+
+    /// Returns the MacroVector the full score (see [`CvssV4::calculated_full_score`]) is looked
+    /// up with, i.e. the equivalence class values EQ1-EQ6 of the effective metrics.
+    ///
+    /// This helps to reconcile scores with the reference implementation, which shows the
+    /// MacroVector next to the score. Returns None if required base metrics are missing.
+    pub fn macro_vector(&self) -> Option<MacroVector> {
+        let metrics = self.effective_metrics()?;
+        Some(scoring::macro_vector(&metrics, metrics.exploit_maturity))
+    }
+
+    /// Returns whether the score is 0.0 because no system is impacted.
+    ///
+    /// The scoring algorithm short-circuits to 0.0 when all vulnerable and subsequent system
+    /// impacts are None, after applying the modified metrics, regardless of all other metrics.
+    /// Returns false if required base metrics are missing.
+    pub fn is_no_impact_shortcut(&self) -> bool {
+        self.effective_metrics().is_some_and(|m| {
+            scoring::has_no_impact(
+                m.vuln_confidentiality_impact,
+                m.vuln_integrity_impact,
+                m.vuln_availability_impact,
+                m.sub_confidentiality_impact,
+                m.sub_integrity_impact,
+                m.sub_availability_impact,
+            )
+        })
+    }
+
+    /// Removes supplemental metrics that are Not Defined (`X`), e.g. `R:X`.
+    ///
+    /// Not Defined supplemental metrics carry no information, so this does not change the
+    /// meaning of the vector. The stored `vector_string` is left unchanged; use the `Display`
+    /// implementation for the normalized vector string.
+    pub fn normalize_supplemental(&mut self) {
+        if self.safety == Some(Safety::NotDefined) {
+            self.safety = None;
         }
-        if let Some(mvi) = &self.modified_vuln_integrity_impact {
-            write!(f, "/MVI:{}", mvi)?;
+        if self.automatable == Some(Automatable::NotDefined) {
+            self.automatable = None;
         }
-        if let Some(mva) = &self.modified_vuln_availability_impact {
-            write!(f, "/MVA:{}", mva)?;
+        if self.recovery == Some(Recovery::NotDefined) {
+            self.recovery = None;
         }
-        if let Some(msc) = &self.modified_sub_confidentiality_impact {
-            write!(f, "/MSC:{}", msc)?;
+        if self.value_density == Some(ValueDensity::NotDefined) {
+            self.value_density = None;
         }
-        if let Some(msi) = &self.modified_sub_integrity_impact {
-            write!(f, "/MSI:{}", msi)?;
+        if self.vulnerability_response_effort == Some(VulnerabilityResponseEffort::NotDefined) {
+            self.vulnerability_response_effort = None;
         }
-        if let Some(msa) = &self.modified_sub_availability_impact {
-            write!(f, "/MSA:{}", msa)?;
+        if self.provider_urgency == Some(ProviderUrgency::NotDefined) {
+            self.provider_urgency = None;
         }
+    }
 
-        // Supplemental metrics
-        if let Some(s) = &self.safety {
-            write!(f, "/S:{}", s)?;
-        }
-        if let Some(au) = &self.automatable {
-            write!(f, "/AU:{}", au)?;
-        }
-        if let Some(r) = &self.recovery {
-            write!(f, "/R:{}", r)?;
-        }
-        if let Some(v) = &self.value_density {
-            write!(f, "/V:{}", v)?;
+    /// Checks for metric combinations that are valid, but likely data-entry errors.
+    ///
+    /// This is a quality check only; the warnings have no influence on scoring.
+    pub fn lint(&self) -> Vec<LintWarning> {
+        lint::lint(self)
+    }
+
+    /// Calculates the CVSS v4.0 score and returns it along with the appropriate nomenclature.
+    ///
+    /// Returns a tuple of (score, nomenclature) where:
+    /// - score: The calculated CVSS v4.0 score (0.0-10.0), rounded to one decimal place
+    /// - nomenclature: The appropriate nomenclature based on which metrics are present:
+    ///   - CVSS-B: Base metrics only
+    ///   - CVSS-BT: Base + Threat metrics
+    ///   - CVSS-BE: Base + Environmental metrics
+    ///   - CVSS-BTE: Base + Threat + Environmental metrics
+    ///
+    /// Returns None if required base metrics are missing.
+    pub fn calculated_score(&self) -> Option<(f64, Nomenclature)> {
+        let score = scoring::calculate_score(self)?;
+        let rounded_score = score::round_v4(score);
+        let nomenclature = Nomenclature::from(self);
+        Some((rounded_score, nomenclature))
+    }
+
+    /// Returns the intermediate values of [`CvssV4::calculated_score`]: the MacroVector, its
+    /// score, and the mean distance that the interpolation subtracts from it, with the metrics
+    /// that contributed to the score.
+    /// Returns None if required base metrics are missing.
+    ///
+    /// Vectors without impact on the vulnerable and subsequent systems score 0.0 regardless of
+    /// their MacroVector, so both the base value and the mean distance are 0.0 for them.
+    pub fn score_breakdown(&self) -> Option<ScoreBreakdown> {
+        let mut breakdown = scoring::score_breakdown_internal(self, true)?;
+        breakdown.metrics = self
+            .metrics()
+            .into_iter()
+            .filter(|(metric, value)| {
+                value != "X" && Version::V4.metric_group(metric) != Some(MetricGroup::Supplemental)
+            })
+            .map(|(metric, _)| metric)
+            .collect();
+        Some(breakdown)
+    }
+
+    /// Returns the score of [`CvssV4::calculated_score`] with its severity and nomenclature,
+    /// for display in reports, e.g. `9.3 / Critical / CVSS-BT`.
+    /// Returns None if required base metrics are missing.
+    pub fn summary(&self) -> Option<CvssV4Summary> {
+        let (score, nomenclature) = self.calculated_score()?;
+        Some(CvssV4Summary {
+            score,
+            severity: Severity::from_score(score),
+            nomenclature,
+        })
+    }
+}
+
+impl FromStr for CvssV4 {
+    type Err = ParseError;
+
+    /// Parses a CVSS v4.0 vector string.
+    ///
+    /// The scores are not calculated, but the `baseScore` and `baseSeverity` pseudo-metrics are
+    /// accepted like for [`CvssV3::from_str`](crate::v3::CvssV3::from_str).
+    ///
+    /// All base metrics are required; see [`CvssV4::from_str_lenient`] for partial vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cvss = CvssV4::from_str_lenient(s)?;
+        match cvss.missing_base_metric() {
+            Some(metric) => Err(ParseError::MissingRequiredMetric {
+                metric: metric.to_string(),
+            }),
+            None => Ok(cvss),
         }
-        if let Some(re) = &self.vulnerability_response_effort {
-            write!(f, "/RE:{}", re)?;
+    }
+}
+
+impl fmt::Display for CvssV4 {
+    /// Formats the metrics as a vector string, see [`CvssV4::to_vector_string`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_vector_string())
+    }
+}
+
+/// Objects are compared by their calculated base scores, falling back to the stored base score
+/// if the vector is incomplete (see [`CvssV4::calculated_base_score`]), and then by their
+/// normalized vector strings (see [`CvssV4::normalized_vector`]).
+///
+/// Objects are equal if they have the same score and metrics, even if their original vector
+/// strings list the metrics in a different order or write out Not Defined metrics. Scores are
+/// compared with [`f64::total_cmp`], so the order is total and a `Vec<CvssV4>` can be sorted
+/// directly.
+impl PartialEq for CvssV4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CvssV4 {}
+
+/// Hashes the normalized vector string (see [`CvssV4::normalized_vector`]).
+///
+/// The floating-point score fields are intentionally excluded from the hash.
+impl Hash for CvssV4 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized_vector().hash(state);
+    }
+}
+
+impl PartialOrd for CvssV4 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CvssV4 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let score = |c: &CvssV4| OrderedScore(c.calculated_base_score().unwrap_or(c.base_score));
+        score(self)
+            .cmp(&score(other))
+            .then_with(|| self.normalized_vector().cmp(&other.normalized_vector()))
+    }
+}
+
+/// Builds a [`CvssV4`] from its metrics, see [`CvssV4::builder`].
+#[derive(Clone, Debug)]
+pub struct CvssV4Builder {
+    cvss: CvssV4,
+}
+
+impl CvssV4 {
+    /// Returns a builder for constructing a `CvssV4` from its metrics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cvss_rs::v4_0::{
+    ///     AttackComplexity, AttackRequirements, AttackVector, CvssV4, Impact, PrivilegesRequired,
+    ///     SubsequentImpact, UserInteraction,
+    /// };
+    ///
+    /// let cvss = CvssV4::builder()
+    ///     .attack_vector(AttackVector::Network)
+    ///     .attack_complexity(AttackComplexity::Low)
+    ///     .attack_requirements(AttackRequirements::None)
+    ///     .privileges_required(PrivilegesRequired::None)
+    ///     .user_interaction(UserInteraction::None)
+    ///     .vuln_confidentiality_impact(Impact::High)
+    ///     .vuln_integrity_impact(Impact::High)
+    ///     .vuln_availability_impact(Impact::High)
+    ///     .sub_confidentiality_impact(SubsequentImpact::None)
+    ///     .sub_integrity_impact(SubsequentImpact::None)
+    ///     .sub_availability_impact(SubsequentImpact::None)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     cvss.vector_string,
+    ///     "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+    /// );
+    /// assert_eq!(cvss.base_score, 9.3);
+    /// ```
+    pub fn builder() -> CvssV4Builder {
+        CvssV4Builder {
+            cvss: CvssV4::empty("CVSS:4.0".to_string()),
         }
-        if let Some(u) = &self.provider_urgency {
-            write!(f, "/U:{}", u)?;
+    }
+}
+
+impl CvssV4Builder {
+    /// Sets the attack vector metric (AV).
+    pub fn attack_vector(mut self, value: AttackVector) -> Self {
+        self.cvss.attack_vector = Some(value);
+        self
+    }
+
+    /// Sets the attack complexity metric (AC).
+    pub fn attack_complexity(mut self, value: AttackComplexity) -> Self {
+        self.cvss.attack_complexity = Some(value);
+        self
+    }
+
+    /// Sets the attack requirements metric (AT).
+    pub fn attack_requirements(mut self, value: AttackRequirements) -> Self {
+        self.cvss.attack_requirements = Some(value);
+        self
+    }
+
+    /// Sets the privileges required metric (PR).
+    pub fn privileges_required(mut self, value: PrivilegesRequired) -> Self {
+        self.cvss.privileges_required = Some(value);
+        self
+    }
+
+    /// Sets the user interaction metric (UI).
+    pub fn user_interaction(mut self, value: UserInteraction) -> Self {
+        self.cvss.user_interaction = Some(value);
+        self
+    }
+
+    /// Sets the vulnerable system confidentiality impact metric (VC).
+    pub fn vuln_confidentiality_impact(mut self, value: Impact) -> Self {
+        self.cvss.vuln_confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the vulnerable system integrity impact metric (VI).
+    pub fn vuln_integrity_impact(mut self, value: Impact) -> Self {
+        self.cvss.vuln_integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the vulnerable system availability impact metric (VA).
+    pub fn vuln_availability_impact(mut self, value: Impact) -> Self {
+        self.cvss.vuln_availability_impact = Some(value);
+        self
+    }
+
+    /// Sets the subsequent system confidentiality impact metric (SC).
+    pub fn sub_confidentiality_impact(mut self, value: SubsequentImpact) -> Self {
+        self.cvss.sub_confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the subsequent system integrity impact metric (SI).
+    pub fn sub_integrity_impact(mut self, value: SubsequentImpact) -> Self {
+        self.cvss.sub_integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the subsequent system availability impact metric (SA).
+    pub fn sub_availability_impact(mut self, value: SubsequentImpact) -> Self {
+        self.cvss.sub_availability_impact = Some(value);
+        self
+    }
+
+    /// Sets the exploit maturity metric (E).
+    pub fn exploit_maturity(mut self, value: ExploitMaturity) -> Self {
+        self.cvss.exploit_maturity = Some(value);
+        self
+    }
+
+    /// Sets the confidentiality requirement metric (CR).
+    pub fn confidentiality_requirement(mut self, value: Requirement) -> Self {
+        self.cvss.confidentiality_requirement = Some(value);
+        self
+    }
+
+    /// Sets the integrity requirement metric (IR).
+    pub fn integrity_requirement(mut self, value: Requirement) -> Self {
+        self.cvss.integrity_requirement = Some(value);
+        self
+    }
+
+    /// Sets the availability requirement metric (AR).
+    pub fn availability_requirement(mut self, value: Requirement) -> Self {
+        self.cvss.availability_requirement = Some(value);
+        self
+    }
+
+    /// Sets the modified attack vector metric (MAV).
+    pub fn modified_attack_vector(mut self, value: ModifiedAttackVector) -> Self {
+        self.cvss.modified_attack_vector = Some(value);
+        self
+    }
+
+    /// Sets the modified attack complexity metric (MAC).
+    pub fn modified_attack_complexity(mut self, value: ModifiedAttackComplexity) -> Self {
+        self.cvss.modified_attack_complexity = Some(value);
+        self
+    }
+
+    /// Sets the modified attack requirements metric (MAT).
+    pub fn modified_attack_requirements(mut self, value: ModifiedAttackRequirements) -> Self {
+        self.cvss.modified_attack_requirements = Some(value);
+        self
+    }
+
+    /// Sets the modified privileges required metric (MPR).
+    pub fn modified_privileges_required(mut self, value: ModifiedPrivilegesRequired) -> Self {
+        self.cvss.modified_privileges_required = Some(value);
+        self
+    }
+
+    /// Sets the modified user interaction metric (MUI).
+    pub fn modified_user_interaction(mut self, value: ModifiedUserInteraction) -> Self {
+        self.cvss.modified_user_interaction = Some(value);
+        self
+    }
+
+    /// Sets the modified vulnerable system confidentiality impact metric (MVC).
+    pub fn modified_vuln_confidentiality_impact(mut self, value: ModifiedImpact) -> Self {
+        self.cvss.modified_vuln_confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified vulnerable system integrity impact metric (MVI).
+    pub fn modified_vuln_integrity_impact(mut self, value: ModifiedImpact) -> Self {
+        self.cvss.modified_vuln_integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified vulnerable system availability impact metric (MVA).
+    pub fn modified_vuln_availability_impact(mut self, value: ModifiedImpact) -> Self {
+        self.cvss.modified_vuln_availability_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified subsequent system confidentiality impact metric (MSC).
+    pub fn modified_sub_confidentiality_impact(mut self, value: ModifiedSubsequentImpact) -> Self {
+        self.cvss.modified_sub_confidentiality_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified subsequent system integrity impact metric (MSI).
+    pub fn modified_sub_integrity_impact(mut self, value: ModifiedSubsequentImpact) -> Self {
+        self.cvss.modified_sub_integrity_impact = Some(value);
+        self
+    }
+
+    /// Sets the modified subsequent system availability impact metric (MSA).
+    pub fn modified_sub_availability_impact(mut self, value: ModifiedSubsequentImpact) -> Self {
+        self.cvss.modified_sub_availability_impact = Some(value);
+        self
+    }
+
+    /// Sets the safety metric (S).
+    pub fn safety(mut self, value: Safety) -> Self {
+        self.cvss.safety = Some(value);
+        self
+    }
+
+    /// Sets the automatable metric (AU).
+    pub fn automatable(mut self, value: Automatable) -> Self {
+        self.cvss.automatable = Some(value);
+        self
+    }
+
+    /// Sets the recovery metric (R).
+    pub fn recovery(mut self, value: Recovery) -> Self {
+        self.cvss.recovery = Some(value);
+        self
+    }
+
+    /// Sets the value density metric (V).
+    pub fn value_density(mut self, value: ValueDensity) -> Self {
+        self.cvss.value_density = Some(value);
+        self
+    }
+
+    /// Sets the vulnerability response effort metric (RE).
+    pub fn vulnerability_response_effort(mut self, value: VulnerabilityResponseEffort) -> Self {
+        self.cvss.vulnerability_response_effort = Some(value);
+        self
+    }
+
+    /// Sets the provider urgency metric (U).
+    pub fn provider_urgency(mut self, value: ProviderUrgency) -> Self {
+        self.cvss.provider_urgency = Some(value);
+        self
+    }
+
+    /// Builds the `CvssV4`.
+    ///
+    /// The vector string and the base score and severity are computed from the metrics.
+    ///
+    /// # Errors
+    ///
+    /// [`ParseError::MissingRequiredMetric`] naming the first missing base metric.
+    pub fn build(self) -> Result<CvssV4, ParseError> {
+        let mut cvss = self.cvss;
+        if let Some(metric) = cvss.missing_base_metric() {
+            return Err(ParseError::MissingRequiredMetric {
+                metric: metric.to_string(),
+            });
         }
 
-        Ok(())
+        cvss.vector_string = cvss.to_string();
+        if let Some(score) = cvss.calculated_base_score() {
+            cvss.base_score = score;
+            cvss.base_severity = Severity::from_score(score);
+        }
+        Ok(cvss)
     }
 }
@@ -1,7 +1,8 @@
 //! CVSS v4.0 score and nomenclature types.
 
 use super::*;
-use std::fmt;
+use crate::utils::float;
+use core::fmt;
 
 /// CVSS v4.0 Nomenclature indicates the type of metrics used to calculate the score.
 ///
@@ -21,6 +22,18 @@ pub enum Nomenclature {
     CvssBTE,
 }
 
+impl Nomenclature {
+    /// Returns whether scores with this nomenclature include the Threat metrics.
+    pub fn includes_threat(&self) -> bool {
+        matches!(self, Nomenclature::CvssBT | Nomenclature::CvssBTE)
+    }
+
+    /// Returns whether scores with this nomenclature include the Environmental metrics.
+    pub fn includes_environmental(&self) -> bool {
+        matches!(self, Nomenclature::CvssBE | Nomenclature::CvssBTE)
+    }
+}
+
 impl fmt::Display for Nomenclature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -61,6 +74,82 @@ impl From<&CvssV4> for Nomenclature {
     }
 }
 
+/// A CVSS v4.0 score with its severity and nomenclature, as returned by [`CvssV4::summary`].
+///
+/// Displays as e.g. `9.3 / Critical / CVSS-BT`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CvssV4Summary {
+    /// The score, rounded to one decimal place.
+    pub score: f64,
+    /// The qualitative severity rating of the score.
+    pub severity: Severity,
+    /// The metrics the score was calculated from.
+    pub nomenclature: Nomenclature,
+}
+
+impl fmt::Display for CvssV4Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::None => "None",
+            Severity::Low => "Low",
+            Severity::Medium => "Medium",
+            Severity::High => "High",
+            Severity::Critical => "Critical",
+        };
+        write!(
+            f,
+            "{:.1} / {} / {}",
+            self.score, severity, self.nomenclature
+        )
+    }
+}
+
+/// The intermediate values of a CVSS v4.0 score, as returned by [`CvssV4::score_breakdown`].
+///
+/// The score is interpolated from the score of the MacroVector: the `base_value` of the
+/// MacroVector is lowered by the `mean_distance` of the vector to the highest-scoring vector of
+/// its MacroVector, i.e. `score` is `base_value - mean_distance`, rounded to one decimal place.
+/// The equivalence class (EQ) values are those of the MacroVector.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBreakdown {
+    /// The MacroVector of the equivalence classes of the vector.
+    pub macro_vector: MacroVector,
+    /// The score of the MacroVector, looked up from the specification's table.
+    pub base_value: f64,
+    /// The mean of the normalized severity distances, subtracted from `base_value`.
+    pub mean_distance: f64,
+    /// The final score, rounded to one decimal place.
+    pub score: f64,
+    /// The abbreviations of the metrics that contributed to the score, i.e. that are present
+    /// and not Not Defined, in specification order. Supplemental metrics are not included, as
+    /// they do not affect the score.
+    pub metrics: Vec<&'static str>,
+}
+
+/// The metric values actually used by the CVSS v4.0 scoring algorithm.
+///
+/// Modified metrics (e.g. MAV) replace their base counterparts unless they are absent or
+/// `NotDefined`. Absent or `NotDefined` threat and requirement metrics take the values the
+/// specification assumes for scoring: E defaults to Attacked and CR/IR/AR default to High.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EffectiveMetrics {
+    pub attack_vector: AttackVector,
+    pub attack_complexity: AttackComplexity,
+    pub attack_requirements: AttackRequirements,
+    pub privileges_required: PrivilegesRequired,
+    pub user_interaction: UserInteraction,
+    pub vuln_confidentiality_impact: Impact,
+    pub vuln_integrity_impact: Impact,
+    pub vuln_availability_impact: Impact,
+    pub sub_confidentiality_impact: SubsequentImpact,
+    pub sub_integrity_impact: SubsequentImpact,
+    pub sub_availability_impact: SubsequentImpact,
+    pub exploit_maturity: ExploitMaturity,
+    pub confidentiality_requirement: Requirement,
+    pub integrity_requirement: Requirement,
+    pub availability_requirement: Requirement,
+}
+
 /// Rounds a CVSS v4.0 score to one decimal place using the specification's rounding method.
 ///
 /// The specification requires rounding to one decimal place. To stay compatible with
@@ -76,7 +165,7 @@ impl From<&CvssV4> for Nomenclature {
 pub(crate) fn round_v4(value: f64) -> f64 {
     let value = f64::clamp(value, 0.0, 10.0);
     const EPSILON: f64 = 10e-6;
-    ((value + EPSILON) * 10.0).round() / 10.0
+    float::round((value + EPSILON) * 10.0) / 10.0
 }
 
 #[cfg(test)]
@@ -104,4 +193,16 @@ mod tests {
         assert_eq!(Nomenclature::CvssBT.to_string(), "CVSS-BT");
         assert_eq!(Nomenclature::CvssBTE.to_string(), "CVSS-BTE");
     }
+
+    #[test]
+    fn test_nomenclature_includes() {
+        assert!(Nomenclature::CvssBTE.includes_threat());
+        assert!(Nomenclature::CvssBTE.includes_environmental());
+        assert!(Nomenclature::CvssBT.includes_threat());
+        assert!(!Nomenclature::CvssBT.includes_environmental());
+        assert!(!Nomenclature::CvssBE.includes_threat());
+        assert!(Nomenclature::CvssBE.includes_environmental());
+        assert!(!Nomenclature::CvssB.includes_threat());
+        assert!(!Nomenclature::CvssB.includes_environmental());
+    }
 }
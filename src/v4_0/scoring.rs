@@ -17,9 +17,13 @@ pub(crate) enum VectorEq {
     Eq5(u8),
 }
 
-/// Represents a MacroVector tuple (EQ1-EQ6) used for score lookup.
-#[derive(Clone, Hash, PartialEq, Eq, Debug)]
-pub(crate) struct MacroVector {
+/// A MacroVector, the six equivalence class values (EQ1-EQ6) a CVSS v4.0 score is looked up
+/// with.
+///
+/// Each EQ groups some metrics into levels, where 0 is the most severe level. The MacroVector
+/// is formatted as its six EQ digits, e.g. `000000`, like in the reference implementation.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub struct MacroVector {
     pub eq1: u8,
     pub eq2: u8,
     pub eq3: u8,
@@ -40,46 +44,58 @@ impl MacroVector {
         }
     }
 
+    /// Parses a MacroVector from its six EQ digits, e.g. `"000000"`.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let digits: Vec<u8> = s
+            .chars()
+            .map(|c| c.to_digit(10).map(|d| d as u8))
+            .collect::<Option<_>>()?;
+        match digits[..] {
+            [eq1, eq2, eq3, eq4, eq5, eq6] => Some(Self::new(eq1, eq2, eq3, eq4, eq5, eq6)),
+            _ => None,
+        }
+    }
+
     pub fn as_tuple(&self) -> (u8, u8, u8, u8, u8, u8) {
         (self.eq1, self.eq2, self.eq3, self.eq4, self.eq5, self.eq6)
     }
 
-    pub fn incr_eq1(&self) -> Self {
+    pub(crate) fn incr_eq1(&self) -> Self {
         Self {
             eq1: self.eq1 + 1,
             ..*self
         }
     }
 
-    pub fn incr_eq2(&self) -> Self {
+    pub(crate) fn incr_eq2(&self) -> Self {
         Self {
             eq2: self.eq2 + 1,
             ..*self
         }
     }
 
-    pub fn incr_eq3(&self) -> Self {
+    pub(crate) fn incr_eq3(&self) -> Self {
         Self {
             eq3: self.eq3 + 1,
             ..*self
         }
     }
 
-    pub fn incr_eq4(&self) -> Self {
+    pub(crate) fn incr_eq4(&self) -> Self {
         Self {
             eq4: self.eq4 + 1,
             ..*self
         }
     }
 
-    pub fn incr_eq5(&self) -> Self {
+    pub(crate) fn incr_eq5(&self) -> Self {
         Self {
             eq5: self.eq5 + 1,
             ..*self
         }
     }
 
-    pub fn incr_eq6(&self) -> Self {
+    pub(crate) fn incr_eq6(&self) -> Self {
         Self {
             eq6: self.eq6 + 1,
             ..*self
@@ -87,84 +103,99 @@ impl MacroVector {
     }
 }
 
+impl fmt::Display for MacroVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (eq1, eq2, eq3, eq4, eq5, eq6) = self.as_tuple();
+        write!(f, "{eq1}{eq2}{eq3}{eq4}{eq5}{eq6}")
+    }
+}
+
+/// Returns the MacroVector of the effective metrics, with `e` as the exploit maturity.
+pub(crate) fn macro_vector(metrics: &EffectiveMetrics, e: ExploitMaturity) -> MacroVector {
+    MacroVector::new(
+        eq1(
+            metrics.attack_vector,
+            metrics.privileges_required,
+            metrics.user_interaction,
+        ),
+        eq2(metrics.attack_complexity, metrics.attack_requirements),
+        eq3(
+            metrics.vuln_confidentiality_impact,
+            metrics.vuln_integrity_impact,
+            metrics.vuln_availability_impact,
+        ),
+        eq4(
+            metrics.sub_confidentiality_impact,
+            metrics.sub_integrity_impact,
+            metrics.sub_availability_impact,
+        ),
+        eq5(e),
+        eq6(
+            metrics.confidentiality_requirement,
+            metrics.vuln_confidentiality_impact,
+            metrics.integrity_requirement,
+            metrics.vuln_integrity_impact,
+            metrics.availability_requirement,
+            metrics.vuln_availability_impact,
+        ),
+    )
+}
+
 // Helper functions to merge base metrics with modified/environmental metrics
 // Modified metrics override base metrics if present and not NotDefined.
-// Each function explicitly maps Modified* variants to base types so the
-// type system prevents base metrics (AV, AC, etc.) from ever accepting "X".
+// Each Modified* type explicitly maps its variants to the base type in `value()`,
+// so the type system prevents base metrics (AV, AC, etc.) from ever accepting "X".
 
 fn merge_av(base: AttackVector, modified: Option<ModifiedAttackVector>) -> AttackVector {
-    match modified {
-        Some(ModifiedAttackVector::NotDefined) | None => base,
-        Some(ModifiedAttackVector::Network) => AttackVector::Network,
-        Some(ModifiedAttackVector::Adjacent) => AttackVector::Adjacent,
-        Some(ModifiedAttackVector::Local) => AttackVector::Local,
-        Some(ModifiedAttackVector::Physical) => AttackVector::Physical,
-    }
+    modified
+        .and_then(ModifiedAttackVector::value)
+        .unwrap_or(base)
 }
 
 fn merge_ac(
     base: AttackComplexity,
     modified: Option<ModifiedAttackComplexity>,
 ) -> AttackComplexity {
-    match modified {
-        Some(ModifiedAttackComplexity::NotDefined) | None => base,
-        Some(ModifiedAttackComplexity::Low) => AttackComplexity::Low,
-        Some(ModifiedAttackComplexity::High) => AttackComplexity::High,
-    }
+    modified
+        .and_then(ModifiedAttackComplexity::value)
+        .unwrap_or(base)
 }
 
 fn merge_at(
     base: AttackRequirements,
     modified: Option<ModifiedAttackRequirements>,
 ) -> AttackRequirements {
-    match modified {
-        Some(ModifiedAttackRequirements::NotDefined) | None => base,
-        Some(ModifiedAttackRequirements::None) => AttackRequirements::None,
-        Some(ModifiedAttackRequirements::Present) => AttackRequirements::Present,
-    }
+    modified
+        .and_then(ModifiedAttackRequirements::value)
+        .unwrap_or(base)
 }
 
 fn merge_pr(
     base: PrivilegesRequired,
     modified: Option<ModifiedPrivilegesRequired>,
 ) -> PrivilegesRequired {
-    match modified {
-        Some(ModifiedPrivilegesRequired::NotDefined) | None => base,
-        Some(ModifiedPrivilegesRequired::None) => PrivilegesRequired::None,
-        Some(ModifiedPrivilegesRequired::Low) => PrivilegesRequired::Low,
-        Some(ModifiedPrivilegesRequired::High) => PrivilegesRequired::High,
-    }
+    modified
+        .and_then(ModifiedPrivilegesRequired::value)
+        .unwrap_or(base)
 }
 
 fn merge_ui(base: UserInteraction, modified: Option<ModifiedUserInteraction>) -> UserInteraction {
-    match modified {
-        Some(ModifiedUserInteraction::NotDefined) | None => base,
-        Some(ModifiedUserInteraction::None) => UserInteraction::None,
-        Some(ModifiedUserInteraction::Passive) => UserInteraction::Passive,
-        Some(ModifiedUserInteraction::Active) => UserInteraction::Active,
-    }
+    modified
+        .and_then(ModifiedUserInteraction::value)
+        .unwrap_or(base)
 }
 
 fn merge_impact(base: Impact, modified: Option<ModifiedImpact>) -> Impact {
-    match modified {
-        Some(ModifiedImpact::NotDefined) | None => base,
-        Some(ModifiedImpact::High) => Impact::High,
-        Some(ModifiedImpact::Low) => Impact::Low,
-        Some(ModifiedImpact::None) => Impact::None,
-    }
+    modified.and_then(ModifiedImpact::value).unwrap_or(base)
 }
 
 fn merge_subsequent_impact(
     base: SubsequentImpact,
     modified: Option<ModifiedSubsequentImpact>,
 ) -> SubsequentImpact {
-    match modified {
-        Some(ModifiedSubsequentImpact::NotDefined) | None => base,
-        Some(ModifiedSubsequentImpact::Safety) => SubsequentImpact::Safety,
-        Some(ModifiedSubsequentImpact::High) => SubsequentImpact::High,
-        Some(ModifiedSubsequentImpact::Low) => SubsequentImpact::Low,
-        Some(ModifiedSubsequentImpact::Negligible) => SubsequentImpact::None,
-    }
+    modified
+        .and_then(ModifiedSubsequentImpact::value)
+        .unwrap_or(base)
 }
 
 fn merge_exploit_maturity(e: Option<ExploitMaturity>) -> ExploitMaturity {
@@ -279,13 +310,10 @@ fn eq6(
     }
 }
 
-/// Main scoring function that calculates the CVSS v4.0 score.
+/// Merges the base metrics with the modified (environmental) metrics and applies the defaults
+/// for threat and environmental metrics that are absent or `NotDefined`.
 /// Returns None if required base metrics are missing.
-///
-/// If `include_threat_metrics` is false, the E metric will be fixed to Attacked (EQ5=0)
-/// regardless of its actual value. This is used for calculating the "base score" which
-/// excludes threat metrics for backwards compatibility with CVSS v3.x.
-pub fn calculate_score_internal(cvss: &CvssV4, include_threat_metrics: bool) -> Option<f64> {
+pub(crate) fn effective_metrics(cvss: &CvssV4) -> Option<EffectiveMetrics> {
     // Get base metrics - all are required for scoring
     let base_av = cvss.attack_vector.as_ref()?;
     let base_ac = cvss.attack_complexity.as_ref()?;
@@ -300,50 +328,106 @@ pub fn calculate_score_internal(cvss: &CvssV4, include_threat_metrics: bool) ->
     let base_sa = cvss.sub_availability_impact.as_ref()?;
 
     // Merge base metrics with modified/environmental metrics
-    let av = merge_av(*base_av, cvss.modified_attack_vector);
-    let ac = merge_ac(*base_ac, cvss.modified_attack_complexity);
-    let at = merge_at(*base_at, cvss.modified_attack_requirements);
-    let pr = merge_pr(*base_pr, cvss.modified_privileges_required);
-    let ui = merge_ui(*base_ui, cvss.modified_user_interaction);
-    let vc = merge_impact(*base_vc, cvss.modified_vuln_confidentiality_impact);
-    let vi = merge_impact(*base_vi, cvss.modified_vuln_integrity_impact);
-    let va = merge_impact(*base_va, cvss.modified_vuln_availability_impact);
-    let sc = merge_subsequent_impact(*base_sc, cvss.modified_sub_confidentiality_impact);
-    let si = merge_subsequent_impact(*base_si, cvss.modified_sub_integrity_impact);
-    let sa = merge_subsequent_impact(*base_sa, cvss.modified_sub_availability_impact);
-
-    // Merge threat and environmental metrics
+    Some(EffectiveMetrics {
+        attack_vector: merge_av(*base_av, cvss.modified_attack_vector),
+        attack_complexity: merge_ac(*base_ac, cvss.modified_attack_complexity),
+        attack_requirements: merge_at(*base_at, cvss.modified_attack_requirements),
+        privileges_required: merge_pr(*base_pr, cvss.modified_privileges_required),
+        user_interaction: merge_ui(*base_ui, cvss.modified_user_interaction),
+        vuln_confidentiality_impact: merge_impact(
+            *base_vc,
+            cvss.modified_vuln_confidentiality_impact,
+        ),
+        vuln_integrity_impact: merge_impact(*base_vi, cvss.modified_vuln_integrity_impact),
+        vuln_availability_impact: merge_impact(*base_va, cvss.modified_vuln_availability_impact),
+        sub_confidentiality_impact: merge_subsequent_impact(
+            *base_sc,
+            cvss.modified_sub_confidentiality_impact,
+        ),
+        sub_integrity_impact: merge_subsequent_impact(*base_si, cvss.modified_sub_integrity_impact),
+        sub_availability_impact: merge_subsequent_impact(
+            *base_sa,
+            cvss.modified_sub_availability_impact,
+        ),
+        // Merge threat and environmental metrics
+        exploit_maturity: merge_exploit_maturity(cvss.exploit_maturity),
+        confidentiality_requirement: merge_requirement(cvss.confidentiality_requirement),
+        integrity_requirement: merge_requirement(cvss.integrity_requirement),
+        availability_requirement: merge_requirement(cvss.availability_requirement),
+    })
+}
+
+/// Returns whether neither the vulnerable nor the subsequent systems are impacted, in which case
+/// the score is 0.0 regardless of all other metrics.
+pub(crate) fn has_no_impact(
+    vc: Impact,
+    vi: Impact,
+    va: Impact,
+    sc: SubsequentImpact,
+    si: SubsequentImpact,
+    sa: SubsequentImpact,
+) -> bool {
+    [vc, vi, va] == [Impact::None; 3] && [sc, si, sa] == [SubsequentImpact::None; 3]
+}
+
+/// Main scoring function that calculates the CVSS v4.0 score.
+/// Returns None if required base metrics are missing.
+///
+/// If `include_threat_metrics` is false, the E metric will be fixed to Attacked (EQ5=0)
+/// regardless of its actual value. This is used for calculating the "base score" which
+/// excludes threat metrics for backwards compatibility with CVSS v3.x.
+pub fn calculate_score_internal(cvss: &CvssV4, include_threat_metrics: bool) -> Option<f64> {
+    let breakdown = score_breakdown_internal(cvss, include_threat_metrics)?;
+    Some(breakdown.base_value - breakdown.mean_distance)
+}
+
+/// Calculates the intermediate values of the CVSS v4.0 score, see [`calculate_score_internal`].
+///
+/// The contributing metrics are left empty, as they are not needed for scoring.
+pub(crate) fn score_breakdown_internal(
+    cvss: &CvssV4,
+    include_threat_metrics: bool,
+) -> Option<ScoreBreakdown> {
+    let metrics = effective_metrics(cvss)?;
+    let EffectiveMetrics {
+        attack_vector: av,
+        attack_complexity: ac,
+        attack_requirements: at,
+        privileges_required: pr,
+        user_interaction: ui,
+        vuln_confidentiality_impact: vc,
+        vuln_integrity_impact: vi,
+        vuln_availability_impact: va,
+        sub_confidentiality_impact: sc,
+        sub_integrity_impact: si,
+        sub_availability_impact: sa,
+        exploit_maturity,
+        confidentiality_requirement: cr,
+        integrity_requirement: ir,
+        availability_requirement: ar,
+    } = metrics;
+
     // For base score calculation, always use E:A (Attacked) regardless of actual value
     let e = if include_threat_metrics {
-        merge_exploit_maturity(cvss.exploit_maturity)
+        exploit_maturity
     } else {
         ExploitMaturity::Attacked // Base score always uses E:A (EQ5=0)
     };
-    let cr = merge_requirement(cvss.confidentiality_requirement);
-    let ir = merge_requirement(cvss.integrity_requirement);
-    let ar = merge_requirement(cvss.availability_requirement);
+
+    // Calculate MacroVector
+    let macro_vector = macro_vector(&metrics, e);
 
     // Exception for no impact on system (shortcut to 0.0)
-    if vc == Impact::None
-        && vi == Impact::None
-        && va == Impact::None
-        && sc == SubsequentImpact::None
-        && si == SubsequentImpact::None
-        && sa == SubsequentImpact::None
-    {
-        return Some(0.0);
+    if has_no_impact(vc, vi, va, sc, si, sa) {
+        return Some(ScoreBreakdown {
+            macro_vector,
+            base_value: 0.0,
+            mean_distance: 0.0,
+            score: 0.0,
+            metrics: Vec::new(),
+        });
     }
 
-    // Calculate MacroVector
-    let macro_vector = MacroVector::new(
-        eq1(av, pr, ui),
-        eq2(ac, at),
-        eq3(vc, vi, va),
-        eq4(sc, si, sa),
-        eq5(e),
-        eq6(cr, vc, ir, vi, ar, va),
-    );
-
     // Lookup base score from MacroVector
     let value = lookup_global(&macro_vector)?;
 
@@ -543,7 +627,13 @@ pub fn calculate_score_internal(cvss: &CvssV4, include_threat_metrics: bool) ->
     };
 
     // Final score is base score minus mean distance
-    Some(value - mean_distance)
+    Some(ScoreBreakdown {
+        macro_vector,
+        base_value: value,
+        mean_distance,
+        score: score::round_v4(value - mean_distance),
+        metrics: Vec::new(),
+    })
 }
 
 /// Temporary struct to hold parsed max vector metrics
@@ -1,26 +1,496 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Display, EnumString)]
+use crate::Version;
+
+#[derive(Clone, Debug, PartialEq, Display, EnumString)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VersionV2 {
-    #[serde(rename = "2.0")]
+    #[cfg_attr(feature = "serde", serde(rename = "2.0"))]
     #[strum(serialize = "2.0")]
     V2_0,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[derive(Clone, Debug, PartialEq, Display, EnumString)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VersionV3 {
-    #[serde(rename = "3.0")]
+    #[cfg_attr(feature = "serde", serde(rename = "3.0"))]
     #[strum(serialize = "3.0")]
     V3_0,
-    #[serde(rename = "3.1")]
+    #[cfg_attr(feature = "serde", serde(rename = "3.1"))]
     #[strum(serialize = "3.1")]
     V3_1,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Display, EnumString)]
+#[derive(Clone, Debug, PartialEq, Display, EnumString)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VersionV4 {
-    #[serde(rename = "4.0")]
+    #[cfg_attr(feature = "serde", serde(rename = "4.0"))]
     #[strum(serialize = "4.0")]
     V4_0,
 }
+
+/// The group of a metric, as defined by the specification of its version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricGroup {
+    Base,
+    /// Temporal metrics of CVSS v2.0 and v3.x.
+    Temporal,
+    /// Threat metrics of CVSS v4.0.
+    Threat,
+    Environmental,
+    /// Supplemental metrics of CVSS v4.0, which do not affect the score.
+    Supplemental,
+}
+
+use MetricGroup::{Base, Environmental, Supplemental, Temporal, Threat};
+
+/// Metrics defined by CVSS v2.0, in specification order.
+const V2_METRICS: &[(&str, MetricGroup)] = &[
+    ("AV", Base),
+    ("AC", Base),
+    ("Au", Base),
+    ("C", Base),
+    ("I", Base),
+    ("A", Base),
+    ("E", Temporal),
+    ("RL", Temporal),
+    ("RC", Temporal),
+    ("CDP", Environmental),
+    ("TD", Environmental),
+    ("CR", Environmental),
+    ("IR", Environmental),
+    ("AR", Environmental),
+];
+
+/// Metrics defined by CVSS v3.0 and v3.1, in specification order.
+const V3_METRICS: &[(&str, MetricGroup)] = &[
+    ("AV", Base),
+    ("AC", Base),
+    ("PR", Base),
+    ("UI", Base),
+    ("S", Base),
+    ("C", Base),
+    ("I", Base),
+    ("A", Base),
+    ("E", Temporal),
+    ("RL", Temporal),
+    ("RC", Temporal),
+    ("CR", Environmental),
+    ("IR", Environmental),
+    ("AR", Environmental),
+    ("MAV", Environmental),
+    ("MAC", Environmental),
+    ("MPR", Environmental),
+    ("MUI", Environmental),
+    ("MS", Environmental),
+    ("MC", Environmental),
+    ("MI", Environmental),
+    ("MA", Environmental),
+];
+
+/// Metrics defined by CVSS v4.0, in specification order.
+const V4_METRICS: &[(&str, MetricGroup)] = &[
+    ("AV", Base),
+    ("AC", Base),
+    ("AT", Base),
+    ("PR", Base),
+    ("UI", Base),
+    ("VC", Base),
+    ("VI", Base),
+    ("VA", Base),
+    ("SC", Base),
+    ("SI", Base),
+    ("SA", Base),
+    ("E", Threat),
+    ("CR", Environmental),
+    ("IR", Environmental),
+    ("AR", Environmental),
+    ("MAV", Environmental),
+    ("MAC", Environmental),
+    ("MAT", Environmental),
+    ("MPR", Environmental),
+    ("MUI", Environmental),
+    ("MVC", Environmental),
+    ("MVI", Environmental),
+    ("MVA", Environmental),
+    ("MSC", Environmental),
+    ("MSI", Environmental),
+    ("MSA", Environmental),
+    ("S", Supplemental),
+    ("AU", Supplemental),
+    ("R", Supplemental),
+    ("V", Supplemental),
+    ("RE", Supplemental),
+    ("U", Supplemental),
+];
+
+/// Description shared by the security requirement metrics of CVSS v2.0.
+const SECURITY_REQUIREMENT_V2: &str =
+    "These metrics enable the analyst to customize the CVSS score depending on the importance of the affected IT asset to a user's organization, measured in terms of confidentiality, integrity, and availability.";
+
+/// Description shared by the security requirement metrics of CVSS v3.x.
+const SECURITY_REQUIREMENT_V3: &str =
+    "These metrics enable the analyst to customize the CVSS score depending on the importance of the affected IT asset to a user's organization, measured in terms of Confidentiality, Integrity, and Availability.";
+
+/// Description shared by the modified base metrics of CVSS v3.x.
+const MODIFIED_BASE_V3: &str =
+    "These metrics enable the analyst to override individual Base metrics based on specific characteristics of a user's environment.";
+
+/// Description shared by the security requirement metrics of CVSS v4.0.
+const SECURITY_REQUIREMENT_V4: &str =
+    "These metrics enable the consumer to customize the assessment depending on the importance of the affected IT asset to the analyst's organization, measured in terms of Confidentiality, Integrity, and Availability.";
+
+/// Description shared by the modified base metrics of CVSS v4.0.
+const MODIFIED_BASE_V4: &str =
+    "These metrics enable the consumer analyst to override individual Base metric values based on specific characteristics of a user's environment.";
+
+/// Names and descriptions of the CVSS v2.0 metrics, in specification order.
+const V2_DESCRIPTIONS: &[(&str, &str, &str)] = &[
+    ("AV", "Access Vector", "This metric reflects how the vulnerability is exploited."),
+    (
+        "AC",
+        "Access Complexity",
+        "This metric measures the complexity of the attack required to exploit the vulnerability once an attacker has gained access to the target system.",
+    ),
+    (
+        "Au",
+        "Authentication",
+        "This metric measures the number of times an attacker must authenticate to a target in order to exploit a vulnerability.",
+    ),
+    (
+        "C",
+        "Confidentiality Impact",
+        "This metric measures the impact on confidentiality of a successfully exploited vulnerability.",
+    ),
+    (
+        "I",
+        "Integrity Impact",
+        "This metric measures the impact to integrity of a successfully exploited vulnerability.",
+    ),
+    (
+        "A",
+        "Availability Impact",
+        "This metric measures the impact to availability of a successfully exploited vulnerability.",
+    ),
+    (
+        "E",
+        "Exploitability",
+        "This metric measures the current state of exploit techniques or code availability.",
+    ),
+    (
+        "RL",
+        "Remediation Level",
+        "The remediation level of a vulnerability is an important factor for prioritization.",
+    ),
+    (
+        "RC",
+        "Report Confidence",
+        "This metric measures the degree of confidence in the existence of the vulnerability and the credibility of the known technical details.",
+    ),
+    (
+        "CDP",
+        "Collateral Damage Potential",
+        "This metric measures the potential for loss of life or physical assets through damage or theft of property or equipment.",
+    ),
+    ("TD", "Target Distribution", "This metric measures the proportion of vulnerable systems."),
+    ("CR", "Confidentiality Requirement", SECURITY_REQUIREMENT_V2),
+    ("IR", "Integrity Requirement", SECURITY_REQUIREMENT_V2),
+    ("AR", "Availability Requirement", SECURITY_REQUIREMENT_V2),
+];
+
+/// Names and descriptions of the CVSS v3.x metrics, in specification order.
+const V3_DESCRIPTIONS: &[(&str, &str, &str)] = &[
+    (
+        "AV",
+        "Attack Vector",
+        "This metric reflects the context by which vulnerability exploitation is possible.",
+    ),
+    (
+        "AC",
+        "Attack Complexity",
+        "This metric describes the conditions beyond the attacker's control that must exist in order to exploit the vulnerability.",
+    ),
+    (
+        "PR",
+        "Privileges Required",
+        "This metric describes the level of privileges an attacker must possess before successfully exploiting the vulnerability.",
+    ),
+    (
+        "UI",
+        "User Interaction",
+        "This metric captures the requirement for a human user, other than the attacker, to participate in the successful compromise of the vulnerable component.",
+    ),
+    (
+        "S",
+        "Scope",
+        "This metric captures whether a vulnerability in one vulnerable component impacts resources in components beyond its security scope.",
+    ),
+    (
+        "C",
+        "Confidentiality",
+        "This metric measures the impact to the confidentiality of the information resources managed by a software component due to a successfully exploited vulnerability.",
+    ),
+    (
+        "I",
+        "Integrity",
+        "This metric measures the impact to integrity of a successfully exploited vulnerability.",
+    ),
+    (
+        "A",
+        "Availability",
+        "This metric measures the impact to the availability of the impacted component resulting from a successfully exploited vulnerability.",
+    ),
+    (
+        "E",
+        "Exploit Code Maturity",
+        "This metric measures the likelihood of the vulnerability being attacked, and is typically based on the current state of exploit techniques, exploit code availability, or active, \"in-the-wild\" exploitation.",
+    ),
+    (
+        "RL",
+        "Remediation Level",
+        "The Remediation Level of a vulnerability is an important factor for prioritization.",
+    ),
+    (
+        "RC",
+        "Report Confidence",
+        "This metric measures the degree of confidence in the existence of the vulnerability and the credibility of the known technical details.",
+    ),
+    ("CR", "Confidentiality Requirement", SECURITY_REQUIREMENT_V3),
+    ("IR", "Integrity Requirement", SECURITY_REQUIREMENT_V3),
+    ("AR", "Availability Requirement", SECURITY_REQUIREMENT_V3),
+    ("MAV", "Modified Attack Vector", MODIFIED_BASE_V3),
+    ("MAC", "Modified Attack Complexity", MODIFIED_BASE_V3),
+    ("MPR", "Modified Privileges Required", MODIFIED_BASE_V3),
+    ("MUI", "Modified User Interaction", MODIFIED_BASE_V3),
+    ("MS", "Modified Scope", MODIFIED_BASE_V3),
+    ("MC", "Modified Confidentiality", MODIFIED_BASE_V3),
+    ("MI", "Modified Integrity", MODIFIED_BASE_V3),
+    ("MA", "Modified Availability", MODIFIED_BASE_V3),
+];
+
+/// Names and descriptions of the CVSS v4.0 metrics, in specification order.
+const V4_DESCRIPTIONS: &[(&str, &str, &str)] = &[
+    (
+        "AV",
+        "Attack Vector",
+        "This metric reflects the context by which vulnerability exploitation is possible.",
+    ),
+    (
+        "AC",
+        "Attack Complexity",
+        "This metric captures measurable actions that must be taken by the attacker to actively evade or circumvent existing built-in security-enhancing conditions in order to obtain a working exploit.",
+    ),
+    (
+        "AT",
+        "Attack Requirements",
+        "This metric captures the prerequisite deployment and execution conditions or variables of the vulnerable system that enable the attack.",
+    ),
+    (
+        "PR",
+        "Privileges Required",
+        "This metric describes the level of privileges an attacker must possess prior to successfully exploiting the vulnerability.",
+    ),
+    (
+        "UI",
+        "User Interaction",
+        "This metric captures the requirement for a human user, other than the attacker, to participate in the successful compromise of the vulnerable system.",
+    ),
+    (
+        "VC",
+        "Confidentiality Impact to the Vulnerable System",
+        "This metric measures the impact to the confidentiality of the information managed by the vulnerable system due to a successfully exploited vulnerability.",
+    ),
+    (
+        "VI",
+        "Integrity Impact to the Vulnerable System",
+        "This metric measures the impact to integrity of the vulnerable system due to a successfully exploited vulnerability.",
+    ),
+    (
+        "VA",
+        "Availability Impact to the Vulnerable System",
+        "This metric measures the impact to the availability of the vulnerable system resulting from a successfully exploited vulnerability.",
+    ),
+    (
+        "SC",
+        "Confidentiality Impact to the Subsequent System",
+        "This metric measures the impact to the confidentiality of the information managed by the subsequent system due to a successfully exploited vulnerability.",
+    ),
+    (
+        "SI",
+        "Integrity Impact to the Subsequent System",
+        "This metric measures the impact to integrity of the subsequent system due to a successfully exploited vulnerability.",
+    ),
+    (
+        "SA",
+        "Availability Impact to the Subsequent System",
+        "This metric measures the impact to the availability of the subsequent system resulting from a successfully exploited vulnerability.",
+    ),
+    (
+        "E",
+        "Exploit Maturity",
+        "This metric measures the likelihood of the vulnerability being attacked, and is based on the current state of exploit techniques, exploit code availability, or active, \"in-the-wild\" exploitation.",
+    ),
+    ("CR", "Confidentiality Requirement", SECURITY_REQUIREMENT_V4),
+    ("IR", "Integrity Requirement", SECURITY_REQUIREMENT_V4),
+    ("AR", "Availability Requirement", SECURITY_REQUIREMENT_V4),
+    ("MAV", "Modified Attack Vector", MODIFIED_BASE_V4),
+    ("MAC", "Modified Attack Complexity", MODIFIED_BASE_V4),
+    ("MAT", "Modified Attack Requirements", MODIFIED_BASE_V4),
+    ("MPR", "Modified Privileges Required", MODIFIED_BASE_V4),
+    ("MUI", "Modified User Interaction", MODIFIED_BASE_V4),
+    ("MVC", "Modified Confidentiality Impact to the Vulnerable System", MODIFIED_BASE_V4),
+    ("MVI", "Modified Integrity Impact to the Vulnerable System", MODIFIED_BASE_V4),
+    ("MVA", "Modified Availability Impact to the Vulnerable System", MODIFIED_BASE_V4),
+    ("MSC", "Modified Confidentiality Impact to the Subsequent System", MODIFIED_BASE_V4),
+    ("MSI", "Modified Integrity Impact to the Subsequent System", MODIFIED_BASE_V4),
+    ("MSA", "Modified Availability Impact to the Subsequent System", MODIFIED_BASE_V4),
+    (
+        "S",
+        "Safety",
+        "This metric measures the impact regarding the safety of a human actor or participant that can be predictably injured as a result of the vulnerability being exploited.",
+    ),
+    (
+        "AU",
+        "Automatable",
+        "This metric captures the answer to the question \"Can an attacker automate exploitation events for this vulnerability across multiple targets?\"",
+    ),
+    (
+        "R",
+        "Recovery",
+        "This metric describes the resilience of a system to recover services, in terms of performance and availability, after an attack has been performed.",
+    ),
+    (
+        "V",
+        "Value Density",
+        "This metric describes the resources that the attacker will gain control over with a single exploitation event.",
+    ),
+    (
+        "RE",
+        "Vulnerability Response Effort",
+        "This metric provides supplemental information on how difficult it is for consumers to provide an initial response to the impact of vulnerabilities for deployed products and services in their infrastructure.",
+    ),
+    (
+        "U",
+        "Provider Urgency",
+        "This metric is an optional \"pass-through\" metric for the urgency of the vulnerability as assessed by the provider.",
+    ),
+];
+
+impl Version {
+    fn registry(&self) -> &'static [(&'static str, MetricGroup)] {
+        match self {
+            Version::V2 => V2_METRICS,
+            Version::V3_0 | Version::V3_1 => V3_METRICS,
+            Version::V4 => V4_METRICS,
+        }
+    }
+
+    /// Returns a numeric code for the version: 200, 300, 310 or 400.
+    ///
+    /// Codes are ordered like the versions, so they can be used as a compact sort key, e.g. in a
+    /// database column.
+    pub fn as_code(&self) -> u16 {
+        match self {
+            Version::V2 => 200,
+            Version::V3_0 => 300,
+            Version::V3_1 => 310,
+            Version::V4 => 400,
+        }
+    }
+
+    /// Returns the version of a code returned by [`Version::as_code`], or None if the code is
+    /// unknown.
+    pub fn from_code(code: u16) -> Option<Version> {
+        match code {
+            200 => Some(Version::V2),
+            300 => Some(Version::V3_0),
+            310 => Some(Version::V3_1),
+            400 => Some(Version::V4),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the version has been superseded and new assessments should use a newer
+    /// version instead.
+    ///
+    /// CVSS v2.0 is deprecated, e.g. NVD no longer provides new v2.0 assessments, and CVSS v3.0
+    /// is superseded by v3.1, which only clarifies the specification and adjusts the
+    /// environmental formula. CVSS v3.1 is still widely used and is not considered deprecated,
+    /// even though v4.0 succeeds it.
+    pub fn is_deprecated(&self) -> bool {
+        matches!(self, Version::V2 | Version::V3_0)
+    }
+
+    /// Returns the recommended version to rescore with, or None for the latest version.
+    ///
+    /// Deprecated versions point to CVSS v3.1, which most databases still publish, and CVSS
+    /// v3.1 points to v4.0.
+    pub fn successor(&self) -> Option<Version> {
+        match self {
+            Version::V2 | Version::V3_0 => Some(Version::V3_1),
+            Version::V3_1 => Some(Version::V4),
+            Version::V4 => None,
+        }
+    }
+
+    /// Returns the abbreviations of all metrics defined by this version, in specification order.
+    ///
+    /// # Ambiguous abbreviations
+    ///
+    /// Some abbreviations are defined by several versions with a different meaning, so a metric
+    /// is always interpreted according to the version of its vector:
+    ///
+    /// | Key  | v2.0                  | v3.x                        | v4.0                          |
+    /// |------|-----------------------|-----------------------------|-------------------------------|
+    /// | `A`  | Availability Impact   | Availability Impact         | not defined (`VA`, `SA`)      |
+    /// | `S`  | not defined           | Scope (`U`, `C`)            | Safety (`N`, `P`)             |
+    /// | `E`  | Exploitability (`POC`, ...) | Exploit Code Maturity (`P`, ...) | Exploit Maturity (`A`, `P`, `U`) |
+    /// | `R`  | not defined           | not defined                 | Recovery (`A`, `U`, `I`)      |
+    /// | `AU` | Authentication (`Au`) | not defined                 | Automatable (`N`, `Y`)        |
+    /// | `AT` | not defined           | not defined                 | Attack Requirements (`N`, `P`) |
+    pub fn metrics(&self) -> impl Iterator<Item = &'static str> {
+        self.registry().iter().map(|(metric, _)| *metric)
+    }
+
+    /// Returns `(abbreviation, name, description)` of all metrics defined by this version, in
+    /// specification order, e.g. `("AV", "Attack Vector", "This metric reflects the context by
+    /// which vulnerability exploitation is possible.")`.
+    ///
+    /// Names and descriptions are taken from the specification of the version, shortened to
+    /// their first sentence, for use in help texts and tooltips.
+    pub fn metric_descriptions(&self) -> &'static [(&'static str, &'static str, &'static str)] {
+        match self {
+            Version::V2 => V2_DESCRIPTIONS,
+            Version::V3_0 | Version::V3_1 => V3_DESCRIPTIONS,
+            Version::V4 => V4_DESCRIPTIONS,
+        }
+    }
+
+    /// Returns the abbreviations of the metrics of `group`, in specification order.
+    pub fn metrics_in_group(&self, group: MetricGroup) -> impl Iterator<Item = &'static str> {
+        self.registry()
+            .iter()
+            .filter(move |(_, g)| *g == group)
+            .map(|(metric, _)| *metric)
+    }
+
+    /// Returns the group of the metric `abbr`, or None if it is not a metric of this version.
+    ///
+    /// The abbreviation is matched case-insensitively, like in vector strings.
+    pub fn metric_group(&self, abbr: &str) -> Option<MetricGroup> {
+        self.registry()
+            .iter()
+            .find(|(metric, _)| metric.eq_ignore_ascii_case(abbr))
+            .map(|(_, group)| *group)
+    }
+
+    /// Returns whether `abbr` is a metric of this version.
+    ///
+    /// The abbreviation is matched case-insensitively, like in vector strings, so both `"Au"`
+    /// and `"AU"` are CVSS v2.0 metrics.
+    pub fn supports_metric(&self, abbr: &str) -> bool {
+        self.metric_group(abbr).is_some()
+    }
+}
@@ -0,0 +1,409 @@
+use cvss_rs::v3::CvssV3;
+use cvss_rs::{Cvss, Severity, Version};
+use rstest::rstest;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X",
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+)]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X/RL:O/MAV:X",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/RL:O"
+)]
+#[case(
+    "AV:N/AC:L/Au:N/C:C/I:C/A:C/E:ND/RL:OF",
+    "AV:N/AC:L/Au:N/C:C/I:C/A:C/RL:OF"
+)]
+fn test_display_omits_not_defined_metrics(#[case] vector: &str, #[case] compact: &str) {
+    let cvss = Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.to_string(), compact);
+    assert_eq!(cvss.to_vector_string(), compact);
+    assert_eq!(cvss.to_vector_string_verbose(), vector);
+    assert_eq!(Cvss::from_str(compact).unwrap().to_vector_string(), compact);
+}
+
+#[test]
+fn test_display_reflects_field_changes() {
+    let mut cvss = Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    if let Cvss::V3_1(inner) = &mut cvss {
+        inner.attack_vector = Some(cvss_rs::v3::AttackVector::Local);
+    }
+
+    assert_eq!(
+        cvss.to_string(),
+        "CVSS:3.1/AV:L/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+    assert_eq!(
+        cvss.vector_string(),
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hash_set_deduplicates_normalized_vectors() {
+    use std::collections::HashSet;
+
+    let scores: HashSet<Cvss> = [
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "CVSS:3.1/S:U/AV:N/AC:L/PR:N/UI:N/C:H/I:H/A:H",
+        "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "AV:N/AC:L/Au:N/C:C/I:C/A:C",
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+        "CVSS:4.0/AC:L/AV:N/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    ]
+    .into_iter()
+    .map(|vector| Cvss::from_str(vector).unwrap())
+    .collect();
+
+    assert_eq!(scores.len(), 4);
+
+    let v3: HashSet<CvssV3> = [
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "CVSS:3.1/C:H/I:H/A:H/AV:N/AC:L/PR:N/UI:N/S:U",
+    ]
+    .into_iter()
+    .map(|vector| CvssV3::from_str(vector).unwrap())
+    .collect();
+
+    assert_eq!(v3.len(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hash_set_ignores_stored_scores() {
+    use std::collections::HashSet;
+
+    let from_json: Cvss = serde_json::from_str(include_str!("data/v3_1_critical.json")).unwrap();
+    let parsed = Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+
+    assert_eq!(from_json.base_score(), 9.8);
+    assert_eq!(parsed.base_score(), 0.0);
+    assert_eq!(from_json, parsed);
+    assert_eq!(HashSet::from([from_json, parsed]).len(), 1);
+}
+
+#[test]
+fn test_filter_min_severity() {
+    let findings: Vec<Cvss> = [
+        include_str!("data/v3_1_critical.json"),
+        include_str!("data/v4_0_example.json"),
+        include_str!("data/v2_0_zero_score.json"),
+        include_str!("data/v3_1_medium.json"),
+        include_str!("data/v2_0_example.json"),
+    ]
+    .iter()
+    .map(|json| serde_json::from_str(json).unwrap())
+    .collect();
+
+    let severe = cvss_rs::filter_min_severity(&findings, cvss_rs::Severity::High);
+
+    let remaining: Vec<(Option<cvss_rs::Severity>, cvss_rs::Version)> = severe
+        .iter()
+        .map(|c| (c.base_severity(), c.version()))
+        .collect();
+    assert_eq!(
+        remaining,
+        vec![
+            (Some(cvss_rs::Severity::Critical), cvss_rs::Version::V3_1),
+            (Some(cvss_rs::Severity::Critical), cvss_rs::Version::V4),
+            (Some(cvss_rs::Severity::High), cvss_rs::Version::V2),
+        ]
+    );
+    assert_eq!(
+        cvss_rs::filter_min_severity(&findings, cvss_rs::Severity::None).len(),
+        findings.len()
+    );
+}
+
+#[test]
+fn test_filter_min_severity_parsed_vectors() {
+    let findings: Vec<Cvss> = [
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N",
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    ]
+    .into_iter()
+    .map(|vector| Cvss::from_str(vector).unwrap())
+    .collect();
+
+    let severe = cvss_rs::filter_min_severity(&findings, cvss_rs::Severity::High);
+
+    assert_eq!(severe, [&findings[0], &findings[2]]);
+}
+
+#[test]
+fn test_confidence_reflects_field_changes() {
+    let mut cvss = Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    assert!((cvss.confidence() - 0.6).abs() < 1e-9);
+
+    if let Cvss::V3_1(inner) = &mut cvss {
+        inner.attack_vector = None;
+    }
+
+    assert!((cvss.confidence() - 0.6 * 7.0 / 8.0).abs() < 1e-9);
+}
+
+#[rstest]
+#[case(
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    true
+)]
+#[case(
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:U/CR:H",
+    true
+)]
+#[case(
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:L",
+    false
+)]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    "AV:N/AC:L/Au:N/C:C/I:C/A:C",
+    false
+)]
+fn test_same_base(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+    let a = Cvss::from_str(a).unwrap();
+    let b = Cvss::from_str(b).unwrap();
+
+    assert_eq!(a.same_base(&b), expected);
+    assert_eq!(b.same_base(&a), expected);
+}
+
+#[rstest]
+#[case("NONE", Severity::None)]
+#[case("low", Severity::Low)]
+#[case("Medium", Severity::Medium)]
+#[case("HIGH", Severity::High)]
+#[case("critical", Severity::Critical)]
+fn test_severity_from_str(#[case] s: &str, #[case] expected: Severity) {
+    let severity = Severity::from_str(s).unwrap();
+
+    assert_eq!(severity, expected);
+    assert_eq!(severity.to_string(), s.to_ascii_uppercase());
+}
+
+#[rstest]
+#[case("")]
+#[case("SEVERE")]
+#[case("CRITICAL ")]
+fn test_severity_from_str_invalid(#[case] s: &str) {
+    assert!(Severity::from_str(s).is_err());
+}
+
+#[rstest]
+#[case("CVSS=CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")]
+#[case("cvss=CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")]
+#[case("  CVSS=CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H\n")]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")]
+fn test_from_assignment(#[case] s: &str) {
+    let cvss = Cvss::from_assignment(s).unwrap();
+
+    assert_eq!(cvss.version(), Version::V3_1);
+    assert_eq!(
+        cvss.vector_string(),
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+}
+
+#[test]
+fn test_from_assignment_invalid_vector() {
+    assert!(Cvss::from_assignment("CVSS=").is_err());
+    assert!(Cvss::from_assignment("SCORE=CVSS:3.1/AV:N").is_err());
+}
+
+#[rstest]
+#[case("AV:N/AC:L/Au:N/C:C/I:C/A:C", Some(Severity::High))]
+#[case("AV:N/AC:L/Au:N/C:N/I:N/A:N", Some(Severity::None))]
+#[case("CVSS:3.0/AV:N/AC:L/PR:L/UI:R/S:C/C:L/I:L/A:N", Some(Severity::Medium))]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    Some(Severity::Critical)
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    Some(Severity::Critical)
+)]
+fn test_computed_severity(#[case] vector: &str, #[case] expected: Option<Severity>) {
+    let cvss = Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.computed_severity(), expected);
+}
+
+#[test]
+fn test_computed_severity_partial_vector() {
+    let cvss = Cvss::from_str_lenient("CVSS:3.1/AV:N/AC:L").unwrap();
+
+    assert_eq!(cvss.computed_severity(), None);
+}
+
+#[rstest]
+#[case("AV:N/AC:L/Au:N/C:C/I:C/A:C", true)]
+#[case("AV:N/AC:L/Au:N/C:C/I:C", false)]
+#[case("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", true)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H", false)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    true
+)]
+#[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N", false)]
+fn test_is_complete(#[case] vector: &str, #[case] expected: bool) {
+    let cvss = Cvss::from_str_lenient(vector).unwrap();
+
+    assert_eq!(cvss.is_complete(), expected);
+    assert_eq!(cvss.calculated_base_score().is_some(), expected);
+}
+
+#[rstest]
+#[case(
+    "CVSS:3.1/S:U/AC:L/AV:N/PR:N/UI:N/C:H/I:H/A:H/E:X/MAV:X",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+)]
+#[case(
+    "CVSS:3.0/av:n/ac:l/pr:n/ui:n/s:u/c:h/i:h/a:h/E:P",
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P"
+)]
+#[case(
+    "AV:N/AC:L/Au:N/C:C/I:C/A:C/E:ND/CDP:H",
+    "AV:N/AC:L/Au:N/C:C/I:C/A:C/CDP:H"
+)]
+#[case(
+    "CVSS:4.0/U:Red/E:X/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/CR:H/S:X",
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/CR:H/U:Red"
+)]
+fn test_normalized_vector(#[case] vector: &str, #[case] expected: &str) {
+    let cvss = Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.normalized_vector(), expected);
+    assert_eq!(Cvss::from_str(expected).unwrap(), cvss);
+}
+
+#[test]
+fn test_cvss_ord_by_base_score_then_version() {
+    let mut findings: Vec<Cvss> = [
+        include_str!("data/v3_1_critical.json"),
+        include_str!("data/v4_0_example.json"),
+        include_str!("data/v2_0_zero_score.json"),
+        include_str!("data/v3_0_critical.json"),
+        include_str!("data/v3_1_medium.json"),
+        include_str!("data/v2_0_example.json"),
+    ]
+    .iter()
+    .map(|json| serde_json::from_str(json).unwrap())
+    .collect();
+
+    findings.sort();
+
+    let sorted: Vec<(f64, Version)> = findings
+        .iter()
+        .map(|c| (c.base_score(), c.version()))
+        .collect();
+    assert_eq!(
+        sorted,
+        vec![
+            (0.0, Version::V2),
+            (5.8, Version::V3_1),
+            (7.5, Version::V2),
+            (9.3, Version::V4),
+            (9.8, Version::V3_0),
+            (9.8, Version::V3_1),
+        ]
+    );
+    assert!(findings[4] < findings[5]);
+    assert_eq!(findings.iter().max(), findings.last());
+}
+
+#[test]
+fn test_sort_key_breaks_ties_by_version() {
+    let mut findings: Vec<Cvss> = [
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N",
+        "AV:N/AC:L/Au:N/C:N/I:N/A:N",
+        "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N",
+    ]
+    .iter()
+    .map(|v| Cvss::from_str(v).unwrap())
+    .collect();
+    findings.push(serde_json::from_str(include_str!("data/v2_0_example.json")).unwrap());
+
+    findings.sort_by_key(Cvss::sort_key);
+
+    let versions: Vec<Version> = findings.iter().map(Cvss::version).collect();
+    assert_eq!(
+        versions,
+        vec![
+            Version::V2,
+            Version::V3_0,
+            Version::V3_1,
+            Version::V4,
+            Version::V2,
+        ]
+    );
+    assert_eq!(findings[4].base_score(), 7.5);
+}
+
+#[test]
+fn test_sort_key_uses_calculated_scores() {
+    let critical = Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    let low =
+        Cvss::from_str("CVSS:4.0/AV:P/AC:H/AT:P/PR:H/UI:A/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N").unwrap();
+
+    assert!(critical.sort_key() > low.sort_key());
+    assert_eq!(critical.cmp(&low), Ordering::Greater);
+}
+
+#[test]
+fn test_severity_cmp_across_versions() {
+    let v2 = Cvss::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:P").unwrap();
+    let v4 =
+        Cvss::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N").unwrap();
+
+    assert_eq!(v2.calculated_base_score(), Some(7.5));
+    assert_eq!(v4.calculated_base_score(), Some(9.3));
+    assert_eq!(v2.severity_cmp(&v4), Ordering::Less);
+    assert_eq!(v4.severity_cmp(&v2), Ordering::Greater);
+    assert_eq!(v4.severity_cmp(&v4), Ordering::Equal);
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AC:L/AV:N/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    "CVSS_V4",
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+)]
+#[case(
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    "CVSS_V3",
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+)]
+#[case("AV:N/AC:L/Au:N/C:P/I:P/A:P", "CVSS_V2", "AV:N/AC:L/Au:N/C:P/I:P/A:P")]
+fn test_to_osv_severity(#[case] vector: &str, #[case] osv_type: &str, #[case] score: &str) {
+    let cvss = Cvss::from_str(vector).unwrap();
+
+    assert_eq!(
+        cvss.to_osv_severity(),
+        serde_json::json!({ "type": osv_type, "score": score })
+    );
+}
+
+#[rstest]
+#[case(0.0, Severity::None)]
+#[case(0.1, Severity::Low)]
+#[case(3.9, Severity::Low)]
+#[case(4.0, Severity::Medium)]
+#[case(6.9, Severity::Medium)]
+#[case(7.0, Severity::High)]
+#[case(8.9, Severity::High)]
+#[case(9.0, Severity::Critical)]
+#[case(10.0, Severity::Critical)]
+#[case(f64::NAN, Severity::None)]
+fn test_severity_from_score(#[case] score: f64, #[case] expected: Severity) {
+    assert_eq!(Severity::from_score(score), expected);
+}
@@ -0,0 +1,86 @@
+use cvss_rs as cvss;
+use cvss_rs::ingest::{
+    scores_from_cve_json, sourced_scores_from_cve_json, try_scores_from_cve_json,
+    try_sourced_scores_from_cve_json, ScoreSource,
+};
+
+#[test]
+fn test_scores_from_cve_json() {
+    let input_json = include_bytes!("data/cve_record.json");
+    let scores = scores_from_cve_json(input_json);
+
+    assert_eq!(scores.len(), 2);
+    assert_eq!(scores[0].version(), cvss::Version::V4);
+    assert_eq!(scores[0].base_score(), 5.9);
+    assert_eq!(scores[1].version(), cvss::Version::V3_1);
+    assert_eq!(scores[1].base_score(), 9.8);
+    assert_eq!(scores[1].base_severity(), Some(cvss::Severity::Critical));
+}
+
+#[test]
+fn test_scores_from_cve_json_skips_invalid_entries() {
+    let input_json = br#"{
+      "containers": {
+        "cna": {
+          "metrics": [
+            { "cvssV3_1": { "vectorString": "CVSS:3.1/AV:N" } },
+            { "cvssV2_0": { "version": "2.0", "vectorString": "AV:N/AC:L/Au:N/C:P/I:P/A:P", "baseScore": 7.5 } }
+          ]
+        }
+      }
+    }"#;
+
+    let scores = scores_from_cve_json(input_json);
+    assert_eq!(scores.len(), 1);
+    assert_eq!(scores[0].version(), cvss::Version::V2);
+
+    assert!(try_scores_from_cve_json(input_json).is_err());
+}
+
+#[test]
+fn test_scores_from_cve_json_without_metrics() {
+    let input_json = br#"{ "containers": { "cna": {} } }"#;
+
+    assert!(scores_from_cve_json(input_json).is_empty());
+    assert!(scores_from_cve_json(b"not json").is_empty());
+    assert!(try_scores_from_cve_json(b"not json").is_err());
+}
+
+#[test]
+fn test_sourced_scores_from_cve_json_includes_adp_containers() {
+    let input_json = include_bytes!("data/cve_record_adp.json");
+
+    let scores = sourced_scores_from_cve_json(input_json);
+    let sources: Vec<(ScoreSource, f64)> = scores
+        .iter()
+        .map(|(source, cvss)| (source.clone(), cvss.base_score()))
+        .collect();
+    assert_eq!(
+        sources,
+        vec![
+            (ScoreSource::Cna, 6.5),
+            (
+                ScoreSource::Adp {
+                    short_name: Some("CISA-ADP".to_string())
+                },
+                9.8
+            ),
+        ]
+    );
+    assert_eq!(
+        try_sourced_scores_from_cve_json(input_json).unwrap(),
+        scores
+    );
+
+    // the CNA-only helpers are unchanged
+    assert_eq!(scores_from_cve_json(input_json).len(), 1);
+}
+
+#[test]
+fn test_sourced_scores_from_cve_json_without_adp() {
+    let input_json = include_bytes!("data/cve_record.json");
+
+    let scores = sourced_scores_from_cve_json(input_json);
+    assert_eq!(scores.len(), 2);
+    assert!(scores.iter().all(|(source, _)| *source == ScoreSource::Cna));
+}
@@ -0,0 +1,102 @@
+use cvss_rs as cvss;
+use cvss_rs::nvd::from_nvd_metrics;
+
+#[test]
+fn test_nvd_metrics_v3_1_and_v2_0() {
+    let input_json = include_str!("data/nvd_metrics.json");
+    let metrics: serde_json::Value = serde_json::from_str(input_json).unwrap();
+
+    let scores = from_nvd_metrics(&metrics);
+
+    assert_eq!(scores.len(), 2);
+    assert_eq!(scores[0].version(), cvss::Version::V3_1);
+    assert_eq!(scores[0].base_score(), 9.8);
+    assert_eq!(scores[0].base_severity(), Some(cvss::Severity::Critical));
+    assert_eq!(scores[1].version(), cvss::Version::V2);
+    assert_eq!(scores[1].base_score(), 7.5);
+    assert_eq!(scores[1].base_severity(), Some(cvss::Severity::High));
+}
+
+#[test]
+fn test_nvd_metrics_subscores() {
+    let input_json = include_str!("data/nvd_metrics.json");
+    let metrics: serde_json::Value = serde_json::from_str(input_json).unwrap();
+
+    let scores = from_nvd_metrics(&metrics);
+
+    let cvss::Cvss::V3_1(v3) = &scores[0] else {
+        panic!("Expected Cvss::V3_1 variant");
+    };
+    assert_eq!(v3.exploitability_score, Some(3.9));
+    assert_eq!(v3.impact_score, Some(5.9));
+    assert!(v3.subscores_consistent());
+
+    let cvss::Cvss::V2(v2) = &scores[1] else {
+        panic!("Expected Cvss::V2 variant");
+    };
+    assert_eq!(v2.exploitability_score, Some(10.0));
+    assert_eq!(v2.impact_score, Some(6.4));
+    assert!(v2.subscores_consistent());
+}
+
+#[test]
+fn test_nvd_metrics_nested_in_cve() {
+    let input_json = include_str!("data/nvd_metrics.json");
+    let metrics: serde_json::Value = serde_json::from_str(input_json).unwrap();
+    let cve = serde_json::json!({ "id": "CVE-2024-0001", "metrics": metrics });
+
+    assert_eq!(from_nvd_metrics(&cve).len(), 2);
+}
+
+#[test]
+fn test_nvd_metrics_skips_invalid_entries() {
+    let metrics = serde_json::json!({
+        "cvssMetricV31": [
+            { "source": "nvd@nist.gov" },
+            { "cvssData": { "version": "3.1", "vectorString": "CVSS:3.1/AV:N" } }
+        ]
+    });
+
+    assert!(from_nvd_metrics(&metrics).is_empty());
+}
+
+#[test]
+fn test_nvd_metrics_version_delta() {
+    let input_json = include_str!("data/nvd_metrics.json");
+    let metrics: serde_json::Value = serde_json::from_str(input_json).unwrap();
+    let scores = from_nvd_metrics(&metrics);
+
+    let groups = cvss::group_by_version(&scores);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].0, cvss::Version::V2);
+    assert_eq!(groups[1].0, cvss::Version::V3_1);
+
+    let (v2, v3) = (groups[0].1[0], groups[1].1[0]);
+    assert_eq!(v2.base_score(), 7.5);
+    assert_eq!(v3.base_score(), 9.8);
+    assert!((cvss::version_delta(v2, v3) - 2.3).abs() < 1e-9);
+    assert!((cvss::version_delta(v3, v2) + 2.3).abs() < 1e-9);
+}
+
+#[test]
+fn test_nvd_v2_vector_spelling() {
+    let input_json = include_str!("data/nvd_metrics.json");
+    let metrics: serde_json::Value = serde_json::from_str(input_json).unwrap();
+    let nvd_vector = metrics["cvssMetricV2"][0]["cvssData"]["vectorString"]
+        .as_str()
+        .unwrap();
+
+    let cvss::Cvss::V2(v2) = &from_nvd_metrics(&metrics)[1] else {
+        panic!("Expected Cvss::V2 variant");
+    };
+    assert_eq!(v2.to_string_nvd(), nvd_vector);
+
+    let parsed = "CVSS:2.0/AU:N/AV:N/AC:L/C:P/I:P/A:P/E:F/CR:H"
+        .parse::<cvss::v2_0::CvssV2>()
+        .unwrap();
+    assert_eq!(parsed.to_string_nvd(), nvd_vector);
+    assert_eq!(
+        parsed.to_string_nvd().to_ascii_uppercase(),
+        "AV:N/AC:L/AU:N/C:P/I:P/A:P"
+    );
+}
@@ -0,0 +1,41 @@
+use cvss_rs::prioritize::{tier, Tier};
+use rstest::rstest;
+
+#[rstest]
+// CVSS 9.0 - 10.0
+#[case(10.0, Some(0.5), Tier::Critical)]
+#[case(9.0, Some(0.5), Tier::Critical)]
+#[case(9.0, Some(0.49), Tier::High)]
+#[case(9.0, None, Tier::High)]
+#[case(9.0, Some(0.09), Tier::High)]
+// CVSS 7.0 - 8.9
+#[case(8.9, Some(0.5), Tier::Critical)]
+#[case(7.0, Some(1.0), Tier::Critical)]
+#[case(7.0, Some(0.1), Tier::High)]
+#[case(7.0, None, Tier::High)]
+#[case(7.0, Some(0.0), Tier::Medium)]
+// CVSS 4.0 - 6.9
+#[case(6.9, Some(0.5), Tier::High)]
+#[case(4.0, Some(0.5), Tier::High)]
+#[case(4.0, Some(0.1), Tier::Medium)]
+#[case(4.0, None, Tier::Medium)]
+#[case(4.0, Some(0.09), Tier::Low)]
+// CVSS 0.1 - 3.9
+#[case(3.9, Some(0.5), Tier::Medium)]
+#[case(0.1, Some(0.5), Tier::Medium)]
+#[case(3.9, None, Tier::Low)]
+#[case(0.1, Some(0.0), Tier::Low)]
+// CVSS 0.0
+#[case(0.0, Some(1.0), Tier::Info)]
+#[case(0.0, None, Tier::Info)]
+fn test_tier(#[case] cvss_score: f64, #[case] epss: Option<f64>, #[case] expected: Tier) {
+    assert_eq!(tier(cvss_score, epss), expected);
+}
+
+#[test]
+fn test_tier_order() {
+    assert!(Tier::Info < Tier::Low);
+    assert!(Tier::Low < Tier::Medium);
+    assert!(Tier::Medium < Tier::High);
+    assert!(Tier::High < Tier::Critical);
+}
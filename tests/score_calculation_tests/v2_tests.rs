@@ -79,9 +79,23 @@ fn test_v2_base_score_with_not_defined_temporal_and_environmental_metrics() {
 fn test_v2_partial_impact_calculation() {
     let vector = "AV:N/AC:L/Au:N/C:P/I:P/A:P";
     // Impact = 10.41 * (1 - (1-0.275)^3) = 6.443...
-    // Exploitability = 20 * 1.0 * 0.71 * 0.704 = 10.0
-    // Score = ((0.6*6.443) + (0.4*10.0) - 1.5) * 1.176 = 7.459... -> round to 7.5
+    // Exploitability = 20 * 1.0 * 0.71 * 0.704 = 9.9968
+    // Score = ((0.6*6.443) + (0.4*9.9968) - 1.5) * 1.176 = 7.484... -> round to 7.5
     assert_v2_base_score(vector, 7.5);
+
+    let cvss = CvssV2::from_str(vector).unwrap();
+    let unrounded = cvss.unrounded_base_score().unwrap();
+    assert!((unrounded - 7.484_659).abs() < 1e-6, "{unrounded}");
+}
+
+#[test]
+fn test_v2_rounds_to_nearest_decimal() {
+    // 6.42... is rounded down to 6.4, where the v3 Roundup would give 6.5
+    let cvss = CvssV2::from_str("AV:N/AC:L/Au:N/C:N/I:P/A:P").unwrap();
+    let unrounded = cvss.unrounded_base_score().unwrap();
+
+    assert!(unrounded > 6.41 && unrounded < 6.45, "{unrounded}");
+    assert_eq!(cvss.calculated_base_score(), Some(6.4));
 }
 
 #[test]
@@ -131,6 +145,28 @@ fn test_real_cve_full(
     );
 }
 
+/// Variations of CVE-2002-0392 isolating the Collateral Damage Potential and Target Distribution
+/// weights. Its adjusted temporal score is 8.3, so the environmental score is
+/// `round((8.3 + (10 - 8.3) * CDP) * TD)`.
+#[rstest]
+#[case::documented("CDP:H/TD:H", 9.2)]
+#[case::not_defined("CDP:ND/TD:ND", 8.3)]
+#[case::cdp_none("CDP:N/TD:H", 8.3)]
+#[case::cdp_low("CDP:L/TD:H", 8.5)]
+#[case::cdp_low_medium("CDP:LM/TD:H", 8.8)]
+#[case::cdp_medium_high("CDP:MH/TD:H", 9.0)]
+#[case::td_none("CDP:H/TD:N", 0.0)]
+#[case::td_low("CDP:H/TD:L", 2.3)]
+#[case::td_medium("CDP:H/TD:M", 6.9)]
+fn test_cve_2002_0392_collateral_damage_and_target_distribution(
+    #[case] environmental: &str,
+    #[case] expected_environmental: f64,
+) {
+    let vector =
+        format!("AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C/{environmental}/CR:M/IR:M/AR:H");
+    assert_v2_scores(&vector, 7.8, Some(6.4), Some(expected_environmental));
+}
+
 /// These are real CVEs with the metrics taken from the official first.org CVSS examples.
 /// Tests for CVEs with base score only.
 #[rstest]
@@ -1,4 +1,4 @@
-use cvss_rs::v3::CvssV3;
+use cvss_rs::v3::{CvssV3, ExploitCodeMaturity, RemediationLevel, ReportConfidence, Scope};
 use rstest::rstest;
 use std::str::FromStr;
 
@@ -80,6 +80,35 @@ fn test_v3_temporal_score_calculation() {
     assert_v3_scores(vector, 9.8, Some(8.8), None);
 }
 
+#[test]
+fn test_v3_temporal_highest_multipliers_keep_base_score() {
+    assert_eq!(ExploitCodeMaturity::High.score(), 1.0);
+    assert_eq!(RemediationLevel::Unavailable.score(), 1.0);
+    assert_eq!(ReportConfidence::Confirmed.score(), 1.0);
+
+    // All temporal multipliers are 1.0, so the temporal score equals the base score
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:H/RL:U/RC:C";
+    assert_v3_scores(vector, 9.8, Some(9.8), None);
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:L/UI:R/S:C/C:L/I:L/A:N/E:H/RL:U/RC:C";
+    assert_v3_scores(vector, 5.4, Some(5.4), None);
+}
+
+#[rstest]
+// Base (9.8) * E(0.91) = 8.918 -> roundup to 9.0
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:U", 9.8, 9.0)]
+// Base (9.8) * E(0.91) * RL(0.95) * RC(0.92) = 7.794... -> roundup to 7.8
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:U/RL:O/RC:U", 9.8, 7.8)]
+// Base (5.4) * E(0.91) = 4.914 -> roundup to 5.0
+#[case("CVSS:3.1/AV:N/AC:L/PR:L/UI:R/S:C/C:L/I:L/A:N/E:U", 5.4, 5.0)]
+fn test_v3_temporal_unproven_exploit_maturity(
+    #[case] vector: &str,
+    #[case] expected_base: f64,
+    #[case] expected_temporal: f64,
+) {
+    assert_eq!(ExploitCodeMaturity::Unproven.score(), 0.91);
+    assert_v3_scores(vector, expected_base, Some(expected_temporal), None);
+}
+
 #[test]
 fn test_v3_environmental_score_calculation() {
     let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/CR:H/IR:H/AR:H";
@@ -88,6 +117,19 @@ fn test_v3_environmental_score_calculation() {
     assert_v3_scores(vector, 9.8, None, Some(9.8));
 }
 
+/// Sets every temporal, modified and requirement metric to a non-default value, so that all
+/// factors of the environmental formula differ from their base counterparts at once.
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:F/RL:W/RC:R/CR:L/IR:M/AR:H/MAV:A/MAC:H/MPR:L/MUI:R/MS:C/MC:L/MI:H/MA:N")]
+#[case("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:F/RL:W/RC:R/CR:L/IR:M/AR:H/MAV:A/MAC:H/MPR:L/MUI:R/MS:C/MC:L/MI:H/MA:N")]
+fn test_v3_environmental_score_all_modified_metrics(#[case] vector: &str) {
+    // MISS = 1 - (1 - 0.5 * 0.22) * (1 - 1.0 * 0.56) * (1 - 1.5 * 0) = 0.6084
+    // Changed modified scope: modified impact ~4.355, modified exploitability
+    // 8.22 * 0.62 * 0.44 * 0.68 * 0.62 ~0.945, roundup(1.08 * 5.30) = 5.8
+    // 5.8 * 0.97 * 0.97 * 0.96 = 5.24 which rounds up to 5.3
+    assert_v3_scores(vector, 9.8, Some(8.9), Some(5.3));
+}
+
 #[test]
 fn test_v3_zero_impact_score() {
     // No impact should result in score 0.0
@@ -175,3 +217,26 @@ fn test_v3_cve_with_some_not_defined() {
 fn test_real_cve_base_only(#[case] vector: &str, #[case] expected_base: f64) {
     assert_v3_base_score(vector, expected_base);
 }
+
+#[test]
+fn test_v3_modified_scope_selects_modified_privileges_weight() {
+    // With MS:C, MPR:L weighs 0.68 instead of 0.62 and the scope-changed impact formula applies
+    let changed = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MPR:L/MS:C";
+    let unchanged = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MPR:L/MS:U";
+    assert_v3_scores(changed, 9.8, None, Some(10.0));
+    assert_v3_scores(unchanged, 9.8, None, Some(8.8));
+
+    let cvss = CvssV3::from_str(changed).unwrap();
+    assert_eq!(cvss.effective_scope(), Scope::Changed);
+    assert_eq!(cvss.scope, Some(Scope::Unchanged));
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/CR:H/IR:H/AR:H", true)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", false)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:L/I:L/A:L/CR:H/IR:H/AR:H", false)]
+fn test_v3_miss_capped(#[case] vector: &str, #[case] expected: bool) {
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.miss_capped(), expected);
+}
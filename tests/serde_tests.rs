@@ -0,0 +1,317 @@
+use cvss_rs as cvss;
+use rstest::rstest;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Advisory {
+    id: String,
+    #[serde(flatten)]
+    cvss: cvss::Cvss,
+}
+
+#[test]
+fn test_flatten_v3_1() {
+    let input_json = r#"{
+      "id": "ADV-2024-0001",
+      "version": "3.1",
+      "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+      "attackVector": "NETWORK",
+      "baseScore": 9.8,
+      "baseSeverity": "CRITICAL"
+    }"#;
+    let advisory: Advisory = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(advisory.id, "ADV-2024-0001");
+    assert_eq!(advisory.cvss.version(), cvss::Version::V3_1);
+    assert_eq!(advisory.cvss.base_score(), 9.8);
+    assert_eq!(
+        advisory.cvss.base_severity().unwrap(),
+        cvss::Severity::Critical
+    );
+}
+
+#[test]
+fn test_flatten_v2_0() {
+    let input_json = r#"{
+      "id": "ADV-2012-0001",
+      "version": "2.0",
+      "vectorString": "AV:N/AC:L/Au:N/C:P/I:P/A:P",
+      "baseScore": 7.5,
+      "severity": "High"
+    }"#;
+    let advisory: Advisory = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(advisory.id, "ADV-2012-0001");
+    assert_eq!(advisory.cvss.version(), cvss::Version::V2);
+    assert_eq!(advisory.cvss.base_score(), 7.5);
+}
+
+#[test]
+fn test_flatten_missing_version_should_error() {
+    let input_json = r#"{
+      "id": "ADV-2024-0002",
+      "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+      "baseScore": 9.8,
+      "baseSeverity": "CRITICAL"
+    }"#;
+
+    assert!(serde_json::from_str::<Advisory>(input_json).is_err());
+}
+
+#[test]
+fn test_v4_0_supplemental_metrics_round_trip() {
+    let input = serde_json::json!({
+        "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/S:P/AU:Y/R:I/V:C/RE:H/U:Red",
+        "baseScore": 9.3,
+        "baseSeverity": "CRITICAL",
+        "attackVector": "NETWORK",
+        "attackComplexity": "LOW",
+        "attackRequirements": "NONE",
+        "privilegesRequired": "NONE",
+        "userInteraction": "NONE",
+        "vulnConfidentialityImpact": "HIGH",
+        "vulnIntegrityImpact": "HIGH",
+        "vulnAvailabilityImpact": "HIGH",
+        "subConfidentialityImpact": "NONE",
+        "subIntegrityImpact": "NONE",
+        "subAvailabilityImpact": "NONE",
+        "Safety": "PRESENT",
+        "Automatable": "YES",
+        "Recovery": "IRRECOVERABLE",
+        "valueDensity": "CONCENTRATED",
+        "vulnerabilityResponseEffort": "HIGH",
+        "providerUrgency": "RED"
+    });
+    let cvss: cvss::v4_0::CvssV4 = serde_json::from_value(input.clone()).unwrap();
+
+    assert_eq!(cvss.safety, Some(cvss::v4_0::Safety::Present));
+    assert_eq!(cvss.automatable, Some(cvss::v4_0::Automatable::Yes));
+    assert_eq!(cvss.recovery, Some(cvss::v4_0::Recovery::Irrecoverable));
+    assert_eq!(
+        cvss.value_density,
+        Some(cvss::v4_0::ValueDensity::Concentrated)
+    );
+    assert_eq!(
+        cvss.vulnerability_response_effort,
+        Some(cvss::v4_0::VulnerabilityResponseEffort::High)
+    );
+    assert_eq!(
+        cvss.provider_urgency,
+        Some(cvss::v4_0::ProviderUrgency::Red)
+    );
+
+    assert_eq!(serde_json::to_value(&cvss).unwrap(), input);
+}
+
+#[rstest]
+#[case(include_str!("data/v2_0_example.json"), "2.0")]
+#[case(include_str!("data/v3_0_critical.json"), "3.0")]
+#[case(include_str!("data/v3_1_critical.json"), "3.1")]
+#[case(include_str!("data/v3_environmental.json"), "3.1")]
+#[case(include_str!("data/v4_0_example.json"), "4.0")]
+fn test_cvss_serialize_round_trip(#[case] input_json: &str, #[case] version: &str) {
+    let cvss: cvss::Cvss = serde_json::from_str(input_json).unwrap();
+
+    let serialized = serde_json::to_string(&cvss).unwrap();
+    let round_tripped: cvss::Cvss = serde_json::from_str(&serialized).unwrap();
+
+    let value: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(value["version"], version);
+    assert_eq!(round_tripped.version(), cvss.version());
+    assert_eq!(
+        serde_json::to_value(&round_tripped).unwrap(),
+        serde_json::to_value(&cvss).unwrap()
+    );
+}
+
+#[test]
+fn test_cvss_serialize_parsed_v3_emits_version_once() {
+    let cvss: cvss::Cvss = "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+        .parse()
+        .unwrap();
+
+    let serialized = serde_json::to_string(&cvss).unwrap();
+
+    assert_eq!(serialized.matches("\"version\"").count(), 1);
+    assert!(serialized.contains("\"version\":\"3.0\""));
+    let round_tripped: cvss::Cvss = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(round_tripped.version(), cvss::Version::V3_0);
+}
+
+#[test]
+fn test_from_json_infer_v3_1_without_version() {
+    let input = serde_json::json!({
+        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "baseScore": 9.8,
+        "baseSeverity": "CRITICAL",
+        "attackVector": "NETWORK"
+    });
+    assert!(serde_json::from_value::<cvss::Cvss>(input.clone()).is_err());
+
+    let cvss = cvss::Cvss::from_json_infer(&input).unwrap();
+
+    assert_eq!(cvss.version(), cvss::Version::V3_1);
+    assert_eq!(cvss.base_score(), 9.8);
+    assert_eq!(cvss.base_severity(), Some(cvss::Severity::Critical));
+}
+
+#[rstest]
+#[case(serde_json::json!({ "baseScore": 9.8 }))]
+#[case(serde_json::json!({ "vectorString": "CVSS:4.0/AV:N", "baseScore": "high" }))]
+fn test_from_json_infer_invalid_json(#[case] input: serde_json::Value) {
+    assert!(matches!(
+        cvss::Cvss::from_json_infer(&input),
+        Err(cvss::ParseError::InvalidJson { .. })
+    ));
+}
+
+#[test]
+fn test_from_json_infer_invalid_prefix() {
+    let input = serde_json::json!({ "vectorString": "CVSS:9.9/AV:N", "baseScore": 0.0 });
+
+    assert!(matches!(
+        cvss::Cvss::from_json_infer(&input),
+        Err(cvss::ParseError::InvalidPrefixVersion { .. })
+    ));
+}
+
+#[rstest]
+#[case(
+    r#"{
+    "version": "3.1",
+    "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P",
+    "baseScore": "9.8",
+    "baseSeverity": "CRITICAL",
+    "temporalScore": " 9.3 "
+}"#
+)]
+#[case(
+    r#"{
+    "version": "2.0",
+    "vectorString": "AV:N/AC:L/Au:N/C:C/I:C/A:C",
+    "baseScore": "9.8",
+    "exploitabilityScore": "10.0"
+}"#
+)]
+#[case(
+    r#"{
+    "version": "4.0",
+    "vectorString": "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    "baseScore": "9.8",
+    "baseSeverity": "CRITICAL"
+}"#
+)]
+fn test_string_encoded_scores(#[case] input_json: &str) {
+    let cvss: cvss::Cvss = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(cvss.base_score(), 9.8);
+}
+
+#[test]
+fn test_string_encoded_score_optional_fields() {
+    let input_json = r#"{
+        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P",
+        "baseScore": 9.8,
+        "baseSeverity": "CRITICAL",
+        "temporalScore": "9.3",
+        "environmentalScore": null
+    }"#;
+    let cvss: cvss::v3::CvssV3 = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(cvss.temporal_score, Some(9.3));
+    assert_eq!(cvss.environmental_score, None);
+    assert_eq!(cvss.impact_score, None);
+}
+
+#[test]
+fn test_invalid_string_encoded_score_should_error() {
+    let input_json = r#"{
+        "version": "3.1",
+        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "baseScore": "high",
+        "baseSeverity": "CRITICAL"
+    }"#;
+
+    assert!(serde_json::from_str::<cvss::Cvss>(input_json).is_err());
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X/MAV:X/MSI:X/U:X",
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    "modifiedAttackVector"
+)]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MAV:X/MS:C",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MS:C",
+    "modifiedAttackVector"
+)]
+#[case(
+    "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:ND/CDP:ND",
+    "AV:N/AC:L/Au:N/C:P/I:P/A:P",
+    "exploitability"
+)]
+fn test_to_minimal_json_omits_not_defined(
+    #[case] vector: &str,
+    #[case] minimal_vector: &str,
+    #[case] omitted: &str,
+) {
+    let cvss: cvss::Cvss = vector.parse().unwrap();
+    let full = serde_json::to_value(&cvss).unwrap();
+    let minimal = cvss.to_minimal_json();
+
+    assert!(full.get(omitted).is_some());
+    assert!(minimal.get(omitted).is_none());
+    assert_eq!(minimal["vectorString"], minimal_vector);
+    assert_eq!(minimal["attackVector"], full["attackVector"]);
+    assert_eq!(minimal["baseScore"], full["baseScore"]);
+    assert_eq!(minimal["version"], full["version"]);
+}
+
+#[test]
+fn test_to_minimal_json_reflects_field_changes() {
+    let mut cvss: cvss::Cvss = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X"
+        .parse()
+        .unwrap();
+    if let cvss::Cvss::V3_1(inner) = &mut cvss {
+        inner.attack_vector = Some(cvss::v3::AttackVector::Local);
+    }
+    let minimal = cvss.to_minimal_json();
+
+    assert_eq!(minimal["attackVector"], "LOCAL");
+    assert_eq!(
+        minimal["vectorString"],
+        "CVSS:3.1/AV:L/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+}
+
+#[test]
+fn test_calculate_scores_from_structured_metrics() {
+    let v3: cvss::v3::CvssV3 = serde_json::from_value(serde_json::json!({
+        "version": "3.1",
+        "baseScore": 0.0,
+        "baseSeverity": "NONE",
+        "attackVector": "NETWORK",
+        "attackComplexity": "LOW",
+        "privilegesRequired": "NONE",
+        "userInteraction": "NONE",
+        "scope": "UNCHANGED",
+        "confidentialityImpact": "HIGH",
+        "integrityImpact": "HIGH",
+        "availabilityImpact": "HIGH"
+    }))
+    .unwrap();
+
+    assert_eq!(v3.vector_string, "");
+    assert_eq!(v3.calculated_base_score(), Some(9.8));
+    assert_eq!(
+        v3.to_vector_string(),
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+
+    let mut v4: cvss::v4_0::CvssV4 =
+        serde_json::from_str(include_str!("data/v4_0_example.json")).unwrap();
+    v4.vector_string.clear();
+
+    assert_eq!(v4.calculated_base_score(), Some(9.3));
+}
@@ -1,5 +1,8 @@
 use cvss_rs as cvss;
-use cvss_rs::{v2_0::CvssV2, ParseError};
+use cvss_rs::{
+    v2_0::{ComplexityMapping, CvssV2, Severity},
+    ParseError,
+};
 use rstest::rstest;
 use std::str::FromStr;
 
@@ -22,13 +25,33 @@ fn test_v2_0_minimal() {
     assert_eq!(cvss.base_score(), 7.5);
 }
 
+#[test]
+fn test_v2_0_zero_score_none_severity() {
+    let input_json = include_str!("data/v2_0_zero_score.json");
+    let cvss: cvss::Cvss = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(cvss.version(), cvss::Version::V2);
+    assert_eq!(cvss.base_score(), 0.0);
+    assert_eq!(cvss.base_severity().unwrap(), cvss::Severity::None);
+}
+
+#[test]
+fn test_v2_0_zero_score_vector_none_severity() {
+    let cvss = cvss::Cvss::from_str("AV:L/AC:H/Au:M/C:N/I:N/A:N").unwrap();
+    assert_eq!(cvss.base_severity(), Some(cvss::Severity::None));
+
+    // Without a stored severity, the severity of non-zero scores is unknown
+    let cvss = cvss::Cvss::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:P").unwrap();
+    assert_eq!(cvss.base_severity(), None);
+}
+
 #[test]
 fn test_v2_0_unknown_metric_should_error() {
     let vector = "AV:N/AC:L/Au:N/C:C/I:C/A:C/XX:H";
 
     assert!(matches!(
         CvssV2::from_str(vector),
-        Err(cvss::ParseError::UnknownMetric { metric }) if metric == "XX"
+        Err(cvss::ParseError::UnknownMetric { metric, .. }) if metric == "XX"
     ));
 }
 
@@ -38,13 +61,14 @@ fn test_v2_0_multiple_unknown_metric_should_error_first() {
 
     assert!(matches!(
         CvssV2::from_str(vector),
-        Err(cvss::ParseError::UnknownMetric { metric }) if metric == "XX"
+        Err(cvss::ParseError::UnknownMetric { metric, .. }) if metric == "XX"
     ));
 }
 
 #[rstest]
 #[case("AV:N/AC:L/Au:N/C:C/I:C/A:C/AV:L", "AV")]
 #[case("AV:N/AC:L/Au:N/C:C/I:C/A:C/AC:H", "AC")]
+#[case("AV:N/AV:L/AC:L/Au:N/C:C/I:C/A:C", "AV")]
 #[case("AV:N/AC:L/Au:N/C:C/I:C/A:C/Au:S", "AU")]
 #[case("AV:N/AC:L/Au:N/C:C/I:C/A:C/C:P", "C")]
 #[case("AV:N/AC:L/Au:N/C:C/I:C/A:C/I:P", "I")]
@@ -60,9 +84,270 @@ fn test_v2_0_multiple_unknown_metric_should_error_first() {
 fn test_v2_0_duplicate_metrics_should_error(#[case] vector: &str, #[case] expected_metric: &str) {
     let result = vector.parse::<CvssV2>();
     assert!(
-        matches!(result, Err(ParseError::DuplicateMetric { ref metric }) if metric == expected_metric),
+        matches!(result, Err(ParseError::DuplicateMetric { ref metric, .. }) if metric == expected_metric),
         "Expected DuplicateMetric error for metric '{}', but got: {:?}",
         expected_metric,
         result
     );
 }
+
+#[test]
+fn test_v2_0_unprefixed_vector_parses_as_v2() {
+    let cvss = cvss::Cvss::from_str("AV:N/AC:L/Au:N/C:C/I:C/A:C").unwrap();
+
+    assert_eq!(cvss.version(), cvss::Version::V2);
+    if let cvss::Cvss::V2(c) = cvss {
+        assert_eq!(c.authentication, Some(cvss::v2_0::Authentication::None));
+    } else {
+        panic!("Expected Cvss::V2 variant");
+    }
+}
+
+#[rstest]
+#[case("AV:N/AC:L/Au:N/C:P/I:P/A:P")]
+#[case("CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P")]
+fn test_v2_0_metrics_string(#[case] vector: &str) {
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.metrics_string(), "AV:N/AC:L/Au:N/C:P/I:P/A:P");
+}
+
+#[test]
+fn test_v2_0_top_contributors() {
+    let cvss = cvss::Cvss::from_str("AV:L/AC:H/Au:M/C:C/I:N/A:N").unwrap();
+
+    assert_eq!(cvss.top_contributors(1), vec![("C", 1.0)]);
+    assert_eq!(cvss.top_contributors(10).len(), 6);
+}
+
+#[rstest]
+#[case(
+    ComplexityMapping::MediumToLow,
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:L/I:L/A:L",
+    7.3
+)]
+#[case(
+    ComplexityMapping::MediumToHigh,
+    "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:L/I:L/A:L",
+    5.6
+)]
+fn test_v2_0_to_v3_approx_medium_complexity(
+    #[case] mapping: ComplexityMapping,
+    #[case] expected_vector: &str,
+    #[case] expected_score: f64,
+) {
+    let cvss = CvssV2::from_str("AV:N/AC:M/Au:N/C:P/I:P/A:P").unwrap();
+    let v3 = cvss.to_v3_approx(mapping).unwrap();
+
+    assert_eq!(v3.vector_string, expected_vector);
+    assert_eq!(v3.base_score, expected_score);
+}
+
+#[rstest]
+#[case(
+    "AV:N/AC:L/Au:N/C:C/I:C/A:C",
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    9.8
+)]
+#[case(
+    "AV:N/AC:M/Au:S/C:P/I:N/A:N",
+    "CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:L/I:N/A:N",
+    4.3
+)]
+#[case(
+    "AV:L/AC:H/Au:M/C:N/I:N/A:N",
+    "CVSS:3.1/AV:L/AC:H/PR:H/UI:N/S:U/C:N/I:N/A:N",
+    0.0
+)]
+fn test_v2_0_to_v3(
+    #[case] vector: &str,
+    #[case] expected_vector: &str,
+    #[case] expected_score: f64,
+) {
+    let cvss = CvssV2::from_str(vector).unwrap();
+    let v3 = cvss.to_v3().unwrap();
+
+    assert_eq!(v3.vector_string, expected_vector);
+    assert_eq!(v3.base_score, expected_score);
+    assert_eq!(v3.calculated_base_score(), Some(expected_score));
+}
+
+#[test]
+fn test_v2_0_to_v3_approx_temporal() {
+    let cvss = CvssV2::from_str("AV:L/AC:H/Au:S/C:C/I:N/A:N/E:POC/RL:OF/RC:UR").unwrap();
+    let v3 = cvss.to_v3_approx(ComplexityMapping::MediumToHigh).unwrap();
+
+    assert_eq!(
+        v3.vector_string,
+        "CVSS:3.1/AV:L/AC:H/PR:L/UI:N/S:U/C:H/I:N/A:N/E:P/RL:O/RC:R"
+    );
+}
+
+#[test]
+fn test_v2_0_confidence_fully_assessed() {
+    let vector = "AV:N/AC:L/Au:N/C:C/I:C/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H";
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+
+    assert!((cvss.confidence() - 1.0).abs() < 1e-9);
+}
+
+#[rstest]
+#[case(0.0, Severity::None)]
+#[case(0.1, Severity::Low)]
+#[case(3.9, Severity::Low)]
+#[case(4.0, Severity::Medium)]
+#[case(6.9, Severity::Medium)]
+#[case(7.0, Severity::High)]
+#[case(10.0, Severity::High)]
+#[case(f64::NAN, Severity::None)]
+fn test_v2_0_severity_from_score_boundaries(#[case] score: f64, #[case] expected: Severity) {
+    assert_eq!(Severity::from_score(score), expected);
+}
+
+#[rstest]
+#[case("AV:L/AC:H/Au:M/C:N/I:N/A:N", Severity::None)]
+#[case("AV:L/AC:H/Au:M/C:P/I:N/A:N", Severity::Low)]
+#[case("AV:N/AC:M/Au:N/C:P/I:N/A:N", Severity::Medium)]
+#[case("AV:N/AC:L/Au:N/C:P/I:P/A:P", Severity::High)]
+fn test_v2_0_calculated_base_severity(#[case] vector: &str, #[case] expected: Severity) {
+    let cvss = CvssV2::from_str(vector).unwrap();
+
+    assert_eq!(cvss.calculated_base_severity(), Some(expected));
+}
+
+#[rstest]
+#[case("AV:N/AC:L/Au:N/C:C/I:C/A:C")]
+#[case("AV:N/AC:L/Au:N/C:P/I:P/A:P/E:POC/RL:OF/RC:C")]
+#[case("AV:N/AC:L/Au:N/C:N/I:N/A:C/E:F/RL:OF/RC:C/CDP:H/TD:H/CR:M/IR:M/AR:H")]
+fn test_v2_0_display_round_trip(#[case] vector: &str) {
+    let cvss = CvssV2::from_str(vector).unwrap();
+
+    assert_eq!(cvss.to_string(), vector);
+}
+
+#[test]
+fn test_v2_0_display_skips_absent_metrics() {
+    let mut cvss = CvssV2::from_str("AV:N/AC:L/Au:N/C:C/I:C/A:C").unwrap();
+    cvss.access_vector = None;
+
+    assert_eq!(cvss.to_string(), "AC:L/Au:N/C:C/I:C/A:C");
+}
+
+#[test]
+fn test_v2_error_position() {
+    let err = CvssV2::from_str("AV:N/AC:L/Au:N/C:P/I:Q/A:P").unwrap_err();
+    assert_eq!(err.position(), Some(19));
+    assert_eq!(
+        err.to_string(),
+        "invalid value 'Q' for metric 'I' at offset 19"
+    );
+
+    let err = CvssV2::from_str("CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P/A:N").unwrap_err();
+    assert_eq!(err.position(), Some(36));
+}
+
+#[test]
+fn test_v2_builder() {
+    use cvss::v2_0::{
+        AccessComplexity, AccessVector, Authentication, Exploitability, Impact, TargetDistribution,
+    };
+
+    let cvss = CvssV2::builder()
+        .access_vector(AccessVector::Network)
+        .access_complexity(AccessComplexity::Low)
+        .authentication(Authentication::None)
+        .confidentiality_impact(Impact::Partial)
+        .integrity_impact(Impact::Partial)
+        .availability_impact(Impact::Partial)
+        .exploitability(Exploitability::Functional)
+        .target_distribution(TargetDistribution::High)
+        .build()
+        .unwrap();
+
+    assert_eq!(cvss.vector_string, "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:F/TD:H");
+    assert_eq!(cvss.base_score, 7.5);
+    assert_eq!(cvss.severity, Some(Severity::High));
+    assert_eq!(cvss.temporal_score, Some(7.1));
+    assert!(cvss.environmental_score.is_some());
+
+    let result = CvssV2::builder()
+        .access_vector(AccessVector::Network)
+        .build();
+    assert_eq!(
+        result.unwrap_err(),
+        ParseError::MissingRequiredMetric {
+            metric: "AC".to_string()
+        }
+    );
+}
+
+#[rstest]
+#[case("AV:N/AC:L/Au:N/C:C/I:C/A:C", 10.000845, 9.9968, 10.0)]
+#[case("AV:N/AC:L/Au:N/C:P/I:P/A:P", 6.442977, 9.9968, 7.5)]
+// No impact: f(Impact) is 0, so the base score is 0.0 despite the exploitability
+#[case("AV:N/AC:L/Au:N/C:N/I:N/A:N", 0.0, 9.9968, 0.0)]
+fn test_v2_0_subscores(
+    #[case] vector: &str,
+    #[case] impact: f64,
+    #[case] exploitability: f64,
+    #[case] base_score: f64,
+) {
+    let cvss = CvssV2::from_str(vector).unwrap();
+
+    assert!((cvss.impact_subscore().unwrap() - impact).abs() < 1e-6);
+    assert!((cvss.exploitability_subscore().unwrap() - exploitability).abs() < 1e-6);
+    assert_eq!(cvss.calculated_base_score(), Some(base_score));
+}
+
+#[test]
+fn test_v2_0_subscores_partial_vector() {
+    let cvss = CvssV2::from_str_lenient("AV:N/AC:L/Au:N").unwrap();
+
+    assert!(cvss.exploitability_subscore().is_some());
+    assert_eq!(cvss.impact_subscore(), None);
+}
+
+#[test]
+fn test_v2_0_score_breakdown() {
+    let cvss = CvssV2::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:P/E:ND/CDP:H/TD:H").unwrap();
+    let breakdown = cvss.score_breakdown().unwrap();
+
+    assert_eq!(breakdown.impact, cvss.impact_subscore().unwrap());
+    assert_eq!(
+        breakdown.exploitability,
+        cvss.exploitability_subscore().unwrap()
+    );
+    assert_eq!(breakdown.base, 7.5);
+    assert_eq!(breakdown.temporal, cvss.calculated_temporal_score());
+    assert_eq!(
+        breakdown.environmental,
+        cvss.calculated_environmental_score()
+    );
+    assert!(breakdown.temporal.is_some() && breakdown.environmental.is_some());
+    assert_eq!(
+        breakdown.metrics,
+        ["AV", "AC", "Au", "C", "I", "A", "CDP", "TD"]
+    );
+
+    let base_only = CvssV2::from_str("AV:N/AC:L/Au:N/C:P/I:P/A:P").unwrap();
+    let breakdown = base_only.score_breakdown().unwrap();
+    assert_eq!(breakdown.temporal, None);
+    assert_eq!(breakdown.environmental, None);
+}
+
+#[test]
+fn test_v2_0_score_pseudo_metrics() {
+    let vector = "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:F/baseScore:7.5/temporalScore:6.2";
+    let cvss = CvssV2::from_str(vector).unwrap();
+
+    assert_eq!(cvss.base_score, 7.5);
+    assert_eq!(cvss.severity, Some(Severity::High));
+    assert_eq!(cvss.temporal_score, Some(6.2));
+    assert_eq!(cvss.vector_string, "AV:N/AC:L/Au:N/C:P/I:P/A:P/E:F");
+
+    let vector = "AV:N/AC:L/Au:N/C:P/I:P/A:P/baseSeverity:MEDIUM/baseScore:7.5";
+    let cvss = CvssV2::from_str(vector).unwrap();
+
+    assert_eq!(cvss.base_score, 7.5);
+    assert_eq!(cvss.severity, Some(Severity::Medium));
+}
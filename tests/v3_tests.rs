@@ -1,7 +1,12 @@
 use cvss::v3::AttackVector;
 use cvss_rs as cvss;
-use cvss_rs::{v3::CvssV3, ParseError};
+use cvss_rs::{
+    v3::{CvssV3, FormulaVersion, Scope, Severity},
+    ParseError,
+};
 use rstest::rstest;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 use std::str::FromStr;
 
 #[test]
@@ -90,7 +95,7 @@ fn test_v3_1_unknown_metric_should_error() {
 
     assert!(matches!(
         CvssV3::from_str(vector),
-        Err(cvss::ParseError::UnknownMetric { metric }) if metric == "XX"
+        Err(cvss::ParseError::UnknownMetric { metric, .. }) if metric == "XX"
     ));
 }
 
@@ -100,11 +105,25 @@ fn test_v3_1_multiple_unknown_metric_should_error_first() {
 
     assert!(matches!(
         CvssV3::from_str(vector),
-        Err(cvss::ParseError::UnknownMetric { metric }) if metric == "XX"
+        Err(cvss::ParseError::UnknownMetric { metric, .. }) if metric == "XX"
     ));
 }
 
 #[rstest]
+#[case("CVSS:3.1/AV:N/AV:L/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", "AV")]
+#[case("CVSS:3.1/av:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/AV:N", "AV")]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/baseScore:9.8/baseScore:5.0",
+    "BASESCORE"
+)]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/baseSeverity:HIGH/baseSeverity:LOW",
+    "BASESEVERITY"
+)]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/temporalScore:9.1/temporalScore:9.1",
+    "TEMPORALSCORE"
+)]
 #[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/AV:L", "AV")]
 #[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/AC:H", "AC")]
 #[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/PR:H", "PR")]
@@ -130,9 +149,706 @@ fn test_v3_1_multiple_unknown_metric_should_error_first() {
 fn test_v3_1_duplicate_metrics_should_error(#[case] vector: &str, #[case] expected_metric: &str) {
     let result = vector.parse::<CvssV3>();
     assert!(
-        matches!(result, Err(ParseError::DuplicateMetric { ref metric }) if metric == expected_metric),
+        matches!(result, Err(ParseError::DuplicateMetric { ref metric, .. }) if metric == expected_metric),
         "Expected DuplicateMetric error for metric '{}', but got: {:?}",
         expected_metric,
         result
     );
 }
+
+#[test]
+fn test_v3_1_feature_vector() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:H/I:L/A:N/E:P";
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+    let features = cvss.feature_vector();
+
+    assert_eq!(features.len(), CvssV3::FEATURE_COLUMNS.len());
+    assert_eq!(features[0], 0.85); // AV:N
+    assert_eq!(features[2], 0.68); // PR:L with scope changed
+    assert_eq!(features[4], 1.0); // S:C
+    assert_eq!(features[6], 0.22); // I:L
+    assert_eq!(features[8], 0.94); // E:P
+    assert_eq!(features[9], -1.0); // RL absent
+    assert_eq!(features[14], -1.0); // MAV absent
+
+    // absent metrics differ from the least severe values
+    let partial = CvssV3::from_str_lenient("CVSS:3.1/AV:N/MAV:X").unwrap();
+    let least = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N").unwrap();
+    assert_eq!(partial.feature_vector()[4..8], [-1.0; 4]);
+    assert_eq!(least.feature_vector()[4..8], [0.0; 4]);
+    assert_eq!(partial.feature_vector()[14], -1.0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_v3_1_from_metric_map() {
+    let map: HashMap<String, String> = [
+        ("AV", "N"),
+        ("AC", "L"),
+        ("PR", "N"),
+        ("UI", "N"),
+        ("S", "U"),
+        ("C", "H"),
+        ("I", "H"),
+        ("A", "h"),
+        ("E", "P"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let cvss = CvssV3::from_metric_map(cvss::Version::V3_1, &map).unwrap();
+
+    assert_eq!(
+        cvss.vector_string,
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P"
+    );
+    assert_eq!(cvss.base_score, 9.8);
+    assert_eq!(cvss.base_severity, Severity::Critical);
+    assert_eq!(cvss.temporal_score, Some(9.3));
+    assert_eq!(cvss.environmental_score, None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_v3_1_from_metric_map_missing_base_metric() {
+    let map: HashMap<String, String> = [("AV", "N"), ("AC", "L")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    assert!(matches!(
+        CvssV3::from_metric_map(cvss::Version::V3_0, &map),
+        Err(ParseError::MissingRequiredMetric { metric }) if metric == "PR"
+    ));
+    assert!(matches!(
+        CvssV3::from_metric_map(cvss::Version::V4, &map),
+        Err(ParseError::InvalidPrefixVersion { .. })
+    ));
+}
+
+#[test]
+fn test_v3_1_metrics_string() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.metrics_string(), "AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H");
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")]
+#[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N")]
+#[case("AV:N/AC:L/Au:N/C:P/I:P/A:P")]
+fn test_into_vector_string(#[case] vector: &str) {
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+    let expected = cvss.vector_string().to_string();
+
+    assert_eq!(cvss.into_vector_string(), expected);
+}
+
+#[rstest]
+#[case(
+    "CVSS:3.1/C:H/AV:N/AC:L/PR:N/UI:N/S:U/I:H/A:H/E:P/MAV:L",
+    vec![
+        ("AV", "N"), ("AC", "L"), ("PR", "N"), ("UI", "N"), ("S", "U"), ("C", "H"), ("I", "H"),
+        ("A", "H"), ("E", "P"), ("MAV", "L"),
+    ]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/U:Red/E:A",
+    vec![
+        ("AV", "N"), ("AC", "L"), ("AT", "N"), ("PR", "N"), ("UI", "N"), ("VC", "H"), ("VI", "H"),
+        ("VA", "H"), ("SC", "N"), ("SI", "N"), ("SA", "N"), ("E", "A"), ("U", "Red"),
+    ]
+)]
+#[case(
+    "AV:N/AC:L/Au:N/C:P/I:P/A:P/CDP:H",
+    vec![("AV", "N"), ("AC", "L"), ("Au", "N"), ("C", "P"), ("I", "P"), ("A", "P"), ("CDP", "H")]
+)]
+fn test_metrics_in_spec_order(#[case] vector: &str, #[case] expected: Vec<(&str, &str)>) {
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+    let metrics: Vec<(&str, String)> = cvss.metrics();
+    let metrics: Vec<(&str, &str)> = metrics.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+    assert_eq!(metrics, expected);
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:H", vec!["AV", "A"])]
+#[case("CVSS:3.1/AV:P/AC:H/PR:H/UI:R/S:U/C:H/I:L/A:N", vec!["C", "UI"])]
+#[case("CVSS:3.0/AV:L/AC:L/PR:N/UI:N/S:C/C:N/I:N/A:N", vec!["AC", "PR"])]
+fn test_v3_top_contributors(#[case] vector: &str, #[case] expected: Vec<&str>) {
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+    let top = cvss.top_contributors(2);
+
+    let metrics: Vec<&str> = top.iter().map(|(metric, _)| *metric).collect();
+    assert_eq!(metrics, expected);
+    assert_eq!(top[0].1, 1.0);
+}
+
+#[test]
+fn test_v3_1_score_pseudo_metrics() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/baseScore:9.8";
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.base_score, 9.8);
+    assert_eq!(cvss.base_severity, Severity::Critical);
+    assert_eq!(cvss.temporal_score, None);
+
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/baseScore:9.8/baseSeverity:CRITICAL/temporalScore:9.3";
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.base_score, 9.8);
+    assert_eq!(cvss.base_severity, Severity::Critical);
+    assert_eq!(cvss.temporal_score, Some(9.3));
+    assert_eq!(
+        cvss.vector_string,
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P"
+    );
+}
+
+#[rstest]
+#[case("baseScore:9.8/baseSeverity:HIGH")]
+#[case("baseSeverity:HIGH/baseScore:9.8")]
+fn test_v3_1_score_pseudo_metrics_order(#[case] scores: &str) {
+    let vector = format!("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/{scores}");
+    let cvss = CvssV3::from_str(&vector).unwrap();
+
+    assert_eq!(cvss.base_score, 9.8);
+    assert_eq!(cvss.base_severity, Severity::High);
+}
+
+#[rstest]
+#[case("baseScore:high", "BASESCORE", "HIGH")]
+#[case("baseScore:10.1", "BASESCORE", "10.1")]
+#[case("baseSeverity:severe", "BASESEVERITY", "SEVERE")]
+fn test_v3_1_invalid_score_pseudo_metric(
+    #[case] component: &str,
+    #[case] metric: &str,
+    #[case] value: &str,
+) {
+    let vector = format!("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/{component}");
+
+    assert_eq!(
+        CvssV3::from_str(&vector),
+        Err(ParseError::InvalidMetricValue {
+            metric: metric.to_string(),
+            value: value.to_string(),
+            position: Some(vector.len() - component.len()),
+        })
+    );
+}
+
+#[test]
+fn test_v3_sarif_security_severity() {
+    let input_json = include_str!("data/v3_1_critical.json");
+    let cvss: cvss::Cvss = serde_json::from_str(input_json).unwrap();
+    assert_eq!(cvss.sarif_security_severity(), "9.8");
+
+    let cvss = cvss::Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N").unwrap();
+    assert_eq!(cvss.sarif_security_severity(), "0.0");
+
+    let cvss = cvss::Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    assert_eq!(cvss.sarif_security_severity(), "9.8");
+}
+
+#[test]
+fn test_v3_1_base_severity_consistent() {
+    let input_json = include_str!("data/v3_1_critical.json");
+    let cvss: CvssV3 = serde_json::from_str(input_json).unwrap();
+    assert!(cvss.base_severity_consistent());
+
+    let mut value: serde_json::Value = serde_json::from_str(input_json).unwrap();
+    value["baseSeverity"] = "HIGH".into();
+    let cvss: CvssV3 = serde_json::from_value(value).unwrap();
+    assert_eq!(cvss.base_score, 9.8);
+    assert!(!cvss.base_severity_consistent());
+}
+
+#[test]
+fn test_v3_1_parse_lossy_collects_all_errors() {
+    let vector = "CVSS:3.1/AV:N/AC:Q/PR:N/UI:N/XX:H/S:U/C:H/I:H/A:H";
+    let (cvss, errors) = cvss::Cvss::parse_lossy(vector);
+
+    assert_eq!(
+        errors,
+        vec![
+            ParseError::InvalidMetricValue {
+                metric: "AC".to_string(),
+                value: "Q".to_string(),
+                position: vector.find("AC:"),
+            },
+            ParseError::UnknownMetric {
+                metric: "XX".to_string(),
+                position: vector.find("XX:"),
+            },
+        ]
+    );
+    let Some(cvss::Cvss::V3_1(cvss)) = cvss else {
+        panic!("Expected Cvss::V3_1 variant");
+    };
+    assert_eq!(cvss.attack_vector, Some(AttackVector::Network));
+    assert_eq!(cvss.attack_complexity, None);
+    assert!(cvss.availability_impact.is_some());
+}
+
+#[rstest]
+#[case("CVSS:9.9/AV:N")]
+#[case("AV:N")]
+fn test_parse_lossy_invalid_prefix(#[case] vector: &str) {
+    let (cvss, errors) = cvss::Cvss::parse_lossy(vector);
+
+    assert!(cvss.is_none());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_parse_list() {
+    let input = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H\n\
+                 \n\
+                 CVSS:3.1/AV:N/AC:Q/PR:N/UI:N/S:U/C:H/I:H/A:H\n  \
+                 CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N  \n";
+    let results = cvss::parse_list(input);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 2);
+    assert_eq!(results[0].as_ref().unwrap().version(), cvss::Version::V3_1);
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().version(), cvss::Version::V4);
+
+    let results = cvss::parse_list(
+        "AV:N/AC:L/Au:N/C:P/I:P/A:P, CVSS:3.0/AV:L/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N,",
+    );
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().version(), cvss::Version::V2);
+    assert_eq!(results[1].as_ref().unwrap().version(), cvss::Version::V3_0);
+}
+
+#[test]
+fn test_v3_1_badge_url() {
+    let input_json = include_str!("data/v3_1_critical.json");
+    let cvss: cvss::Cvss = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(
+        cvss.badge_url("CVSS 3.1"),
+        "https://img.shields.io/badge/CVSS_3.1-9.8-cc0500"
+    );
+    assert_eq!(
+        cvss.badge_url("cvss-v3/base"),
+        "https://img.shields.io/badge/cvss--v3%2Fbase-9.8-cc0500"
+    );
+    assert_eq!(cvss::Severity::Critical.color_hex(), "#cc0500");
+
+    let cvss = cvss::Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    assert_eq!(
+        cvss.badge_url("cvss"),
+        "https://img.shields.io/badge/cvss-9.8-cc0500"
+    );
+}
+
+#[test]
+fn test_v3_1_score_with_custom_scorer() {
+    struct ConstantScorer;
+
+    impl cvss::v3::Scorer for ConstantScorer {
+        fn base_score(&self, _cvss: &CvssV3) -> Option<f64> {
+            Some(4.2)
+        }
+    }
+
+    let cvss = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+
+    assert_eq!(cvss.score_with(&ConstantScorer), Some(4.2));
+    assert_eq!(cvss.score_with(&cvss::v3::SpecScorer), Some(9.8));
+}
+
+#[test]
+fn test_v3_1_nvd_subscores() {
+    let input_json = r#"{
+        "version": "3.1",
+        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:L/I:L/A:N",
+        "baseScore": 6.4,
+        "baseSeverity": "MEDIUM",
+        "attackVector": "NETWORK",
+        "attackComplexity": "LOW",
+        "privilegesRequired": "LOW",
+        "userInteraction": "NONE",
+        "scope": "CHANGED",
+        "confidentialityImpact": "LOW",
+        "integrityImpact": "LOW",
+        "availabilityImpact": "NONE",
+        "exploitabilityScore": 3.1,
+        "impactScore": 2.7
+    }"#;
+    let mut cvss: CvssV3 = serde_json::from_str(input_json).unwrap();
+
+    assert_eq!(cvss.exploitability_score, Some(3.1));
+    assert_eq!(cvss.impact_score, Some(2.7));
+    assert!(cvss.subscores_consistent());
+
+    let json = serde_json::to_value(&cvss).unwrap();
+    assert_eq!(json["exploitabilityScore"], 3.1);
+    assert_eq!(json["impactScore"], 2.7);
+
+    cvss.impact_score = Some(5.9);
+    assert!(!cvss.subscores_consistent());
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 0.914816)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:C/C:L/I:L/A:N", 0.3916)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N", 0.0)]
+fn test_v3_impact_base(#[case] vector: &str, #[case] expected: f64) {
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    let iss = cvss.impact_base().unwrap();
+    assert!((iss - expected).abs() < 1e-9, "{iss} != {expected}");
+}
+
+#[test]
+fn test_v3_subscores() {
+    let cvss = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+
+    let impact = cvss.impact_subscore().unwrap();
+    let exploitability = cvss.exploitability_subscore().unwrap();
+    assert!((impact - 5.9).abs() < 0.05, "{impact}");
+    assert!((exploitability - 3.9).abs() < 0.05, "{exploitability}");
+
+    // The impact is scaled differently if the scope is changed
+    let changed = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H").unwrap();
+    let impact = changed.impact_subscore().unwrap();
+    assert!((impact - 6.0).abs() < 0.05, "{impact}");
+
+    let partial = CvssV3::from_str_lenient("CVSS:3.1/AV:N/AC:L/PR:N").unwrap();
+    assert_eq!(partial.impact_subscore(), None);
+    assert_eq!(partial.exploitability_subscore(), None);
+}
+
+#[test]
+fn test_v3_unrounded_base_score() {
+    let cvss = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+
+    // 3.887042 + 5.873119 rounds up to 9.8
+    let unrounded = cvss.unrounded_base_score().unwrap();
+    assert!(unrounded > 9.7 && unrounded <= 9.8, "{unrounded}");
+    assert!((unrounded - 9.760161).abs() < 1e-6, "{unrounded}");
+    assert_eq!(cvss.calculated_base_score(), Some(9.8));
+
+    let no_impact = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N").unwrap();
+    assert_eq!(no_impact.unrounded_base_score(), Some(0.0));
+
+    let partial = CvssV3::from_str_lenient("CVSS:3.1/AV:N/AC:L").unwrap();
+    assert_eq!(partial.unrounded_base_score(), None);
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N", Severity::None)]
+#[case("CVSS:3.1/AV:N/AC:H/PR:N/UI:R/S:U/C:L/I:N/A:N", Severity::Low)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:L/UI:R/S:C/C:L/I:L/A:N", Severity::Medium)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:H/I:H/A:H", Severity::High)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", Severity::Critical)]
+fn test_v3_calculated_base_severity(#[case] vector: &str, #[case] expected: Severity) {
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.calculated_base_severity(), Some(expected));
+}
+
+#[test]
+fn test_v3_calculated_temporal_and_environmental_severity() {
+    // Base 9.8, temporal 8.9 and environmental 5.3
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:F/RL:W/RC:R/CR:L/IR:M/AR:H/MAV:A/MAC:H/MPR:L/MUI:R/MS:C/MC:L/MI:H/MA:N";
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.calculated_base_severity(), Some(Severity::Critical));
+    assert_eq!(cvss.calculated_temporal_severity(), Some(Severity::High));
+    assert_eq!(
+        cvss.calculated_environmental_severity(),
+        Some(Severity::Medium)
+    );
+
+    let mut cvss = cvss;
+    cvss.attack_vector = None;
+    assert_eq!(cvss.calculated_base_severity(), None);
+    assert_eq!(cvss.calculated_temporal_severity(), None);
+    assert_eq!(cvss.calculated_environmental_severity(), None);
+}
+
+#[rstest]
+#[case(0.0, Severity::None)]
+#[case(0.1, Severity::Low)]
+#[case(3.9, Severity::Low)]
+#[case(4.0, Severity::Medium)]
+#[case(6.9, Severity::Medium)]
+#[case(7.0, Severity::High)]
+#[case(8.9, Severity::High)]
+#[case(9.0, Severity::Critical)]
+#[case(10.0, Severity::Critical)]
+#[case(f64::NAN, Severity::None)]
+fn test_v3_severity_from_score_boundaries(#[case] score: f64, #[case] expected: Severity) {
+    assert_eq!(Severity::from_score(score), expected);
+}
+
+#[test]
+fn test_v3_display_uses_version_field() {
+    let mut cvss = CvssV3::from_str("CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    cvss.vector_string = String::new();
+
+    assert_eq!(
+        cvss.to_string(),
+        "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"
+    );
+}
+
+#[test]
+fn test_v3_display_version_from_json_tag() {
+    let input_json = r#"{
+        "version": "3.0",
+        "vectorString": "AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "baseScore": 9.8,
+        "baseSeverity": "CRITICAL",
+        "attackVector": "NETWORK"
+    }"#;
+    let cvss: cvss::Cvss = serde_json::from_str(input_json).unwrap();
+
+    let cvss::Cvss::V3_0(v3) = cvss else {
+        panic!("Expected Cvss::V3_0 variant");
+    };
+    assert_eq!(v3.version, Some(cvss::version::VersionV3::V3_0));
+    assert_eq!(v3.to_string(), "CVSS:3.0/AV:N");
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 9.8, 10.0)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:L/UI:N/S:U/C:L/I:L/A:N", 5.4, 6.4)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:U/C:L/I:L/A:N", 5.4, 6.1)]
+fn test_v3_score_with_scope(#[case] vector: &str, #[case] unchanged: f64, #[case] changed: f64) {
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.score_with_scope(Scope::Changed), Some(changed));
+    assert_eq!(cvss.score_with_scope(Scope::Unchanged), Some(unchanged));
+    // The scope of the vector itself is unchanged
+    assert_eq!(cvss.scope, Some(Scope::Unchanged));
+    assert_eq!(cvss.calculated_base_score(), Some(unchanged));
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/A:L", 45)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/XX:L", 45)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E", 45)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:Q/C:H/I:H/A:H", 29)]
+fn test_v3_1_error_position(#[case] vector: &str, #[case] offset: usize) {
+    let err = CvssV3::from_str(vector).unwrap_err();
+
+    assert_eq!(err.position(), Some(offset));
+    assert!(err.to_string().ends_with(&format!(" at offset {offset}")));
+}
+
+#[test]
+fn test_v3_builder_matches_parsed_vector() {
+    let vector = "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H/E:P/CR:H/MAV:L";
+    let cvss = CvssV3::builder(cvss::version::VersionV3::V3_0)
+        .confidentiality_impact(cvss::v3::Impact::High)
+        .attack_vector(AttackVector::Network)
+        .attack_complexity(cvss::v3::AttackComplexity::Low)
+        .privileges_required(cvss::v3::PrivilegesRequired::None)
+        .user_interaction(cvss::v3::UserInteraction::None)
+        .scope(Scope::Changed)
+        .integrity_impact(cvss::v3::Impact::High)
+        .availability_impact(cvss::v3::Impact::High)
+        .exploit_code_maturity(cvss::v3::ExploitCodeMaturity::ProofOfConcept)
+        .confidentiality_requirement(cvss::v3::SecurityRequirement::High)
+        .modified_attack_vector(AttackVector::Local)
+        .build()
+        .unwrap();
+    let parsed = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.vector_string, vector);
+    assert_eq!(Some(cvss.base_score), parsed.calculated_base_score());
+    assert_eq!(cvss.temporal_score, parsed.calculated_temporal_score());
+    assert_eq!(
+        cvss.environmental_score,
+        parsed.calculated_environmental_score()
+    );
+    assert!(cvss.temporal_severity.is_some());
+}
+
+#[test]
+fn test_v3_builder_requires_base_metrics() {
+    let result = CvssV3::builder(cvss::version::VersionV3::V3_1)
+        .attack_vector(AttackVector::Network)
+        .attack_complexity(cvss::v3::AttackComplexity::Low)
+        .build();
+
+    assert_eq!(
+        result.unwrap_err(),
+        ParseError::MissingRequiredMetric {
+            metric: "PR".to_string()
+        }
+    );
+}
+
+#[rstest]
+#[case(
+    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H/MS:C",
+    FormulaVersion::V3_0,
+    15
+)]
+#[case(
+    "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H/MS:C",
+    FormulaVersion::V3_1,
+    13
+)]
+fn test_v3_environmental_formula(
+    #[case] vector: &str,
+    #[case] expected: FormulaVersion,
+    #[case] exponent: i32,
+) {
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert_eq!(cvss.environmental_formula(), expected);
+    assert_eq!(cvss.environmental_formula().exponent(), exponent);
+}
+
+#[test]
+fn test_v3_environmental_formula_without_version() {
+    let mut cvss = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H/MS:C").unwrap();
+    cvss.version = None;
+    assert_eq!(cvss.environmental_formula(), FormulaVersion::V3_1);
+    assert!(cvss.to_vector_string().starts_with("CVSS:3.1/"));
+
+    cvss.vector_string = "CVSS:3.0/AV:N".to_string();
+    assert_eq!(cvss.environmental_formula(), FormulaVersion::V3_0);
+    assert!(cvss.to_vector_string().starts_with("CVSS:3.0/"));
+}
+
+#[test]
+fn test_to_vector_string_reflects_field_changes() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+    let mut cvss = CvssV3::from_str(vector).unwrap();
+    cvss.attack_vector = Some(AttackVector::Local);
+    cvss.modified_scope = Some(Scope::Changed);
+
+    let expected = "CVSS:3.1/AV:L/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/MS:C";
+    assert_eq!(cvss.vector_string, vector);
+    assert_eq!(cvss.to_vector_string(), expected);
+    assert_eq!(cvss.to_string(), expected);
+
+    let mut cvss = cvss::Cvss::from_str("CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P").unwrap();
+    if let cvss::Cvss::V2(v2) = &mut cvss {
+        v2.access_vector = Some(cvss::v2_0::AccessVector::Local);
+    }
+    assert_eq!(cvss.to_vector_string(), "AV:L/AC:L/Au:N/C:P/I:P/A:P");
+}
+
+#[rstest]
+#[case(
+    "https://www.first.org/cvss/calculator/3.1#CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    cvss::Version::V3_1
+)]
+#[case(
+    "https://www.first.org/cvss/calculator/3.0#CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+    cvss::Version::V3_0
+)]
+#[case(
+    "https://www.first.org/cvss/calculator/4.0#CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    cvss::Version::V4
+)]
+fn test_from_calculator_url(#[case] url: &str, #[case] version: cvss::Version) {
+    let cvss = cvss::Cvss::from_calculator_url(url).unwrap();
+
+    assert_eq!(cvss.version(), version);
+    assert_eq!(cvss.vector_string(), url.split_once('#').unwrap().1);
+}
+
+#[rstest]
+#[case("https://www.first.org/cvss/calculator/3.1")]
+#[case("https://www.first.org/cvss/calculator/3.1#")]
+#[case("https://www.first.org/cvss/calculator/3.1#AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")]
+fn test_from_calculator_url_without_vector(#[case] url: &str) {
+    assert!(matches!(
+        cvss::Cvss::from_calculator_url(url),
+        Err(ParseError::InvalidPrefixLabel { .. })
+    ));
+}
+
+#[test]
+fn test_from_calculator_url_invalid_vector() {
+    let url = "https://www.first.org/cvss/calculator/3.1#CVSS:3.1/AV:N/AC:L";
+
+    assert_eq!(
+        cvss::Cvss::from_calculator_url(url).unwrap_err(),
+        ParseError::MissingRequiredMetric {
+            metric: "PR".to_string()
+        }
+    );
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:C", 0.94 * 0.95 * 1.0)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:X/RL:X/RC:X", 1.0)]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 1.0)]
+fn test_v3_temporal_multiplier(#[case] vector: &str, #[case] expected: f64) {
+    let cvss = CvssV3::from_str(vector).unwrap();
+
+    assert!((cvss.temporal_multiplier() - expected).abs() < 1e-12);
+}
+
+#[test]
+fn test_v3_score_breakdown() {
+    let cvss =
+        CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/E:P/RL:O/RC:X").unwrap();
+    let breakdown = cvss.score_breakdown().unwrap();
+
+    assert_eq!(breakdown.impact, cvss.impact_subscore().unwrap());
+    assert_eq!(
+        breakdown.exploitability,
+        cvss.exploitability_subscore().unwrap()
+    );
+    assert_eq!(breakdown.base, 9.8);
+    assert_eq!(breakdown.temporal, Some(8.8));
+    assert_eq!(breakdown.environmental, None);
+    assert_eq!(
+        breakdown.metrics,
+        ["AV", "AC", "PR", "UI", "S", "C", "I", "A", "E", "RL"]
+    );
+
+    let partial = CvssV3::from_str_lenient("CVSS:3.1/AV:N/AC:L").unwrap();
+    assert_eq!(partial.score_breakdown(), None);
+}
+
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U", Some((0.0, 9.8)))]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", Some((9.8, 9.8)))]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/C:L/I:N/A:N", Some((5.3, 5.8)))]
+#[case("CVSS:3.1/C:H", Some((3.8, 10.0)))]
+#[case("CVSS:3.1/AV:P/PR:L", Some((0.0, 7.4)))]
+#[case("CVSS:3.0/S:C/A:L", Some((1.8, 10.0)))]
+#[case("CVSS:3.1/", None)]
+fn test_v3_score_range(#[case] vector: &str, #[case] expected: Option<(f64, f64)>) {
+    let cvss = CvssV3::from_str_lenient(vector).unwrap();
+
+    assert_eq!(cvss.score_range(), expected);
+}
+
+#[test]
+fn test_v3_sort_by_calculated_score() {
+    let mut scores: Vec<CvssV3> = [
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+        "CVSS:3.1/AV:L/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N",
+        "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:L/I:N/A:N",
+    ]
+    .into_iter()
+    .map(|vector| CvssV3::from_str(vector).unwrap())
+    .collect();
+    scores.sort();
+
+    let calculated: Vec<_> = scores
+        .iter()
+        .map(|c| c.calculated_base_score().unwrap())
+        .collect();
+    assert_eq!(calculated, [1.8, 5.3, 9.8]);
+
+    let same_score = CvssV3::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:L/A:N").unwrap();
+    assert_ne!(scores[1], same_score);
+    assert!(scores[1] < same_score);
+}
@@ -1,5 +1,8 @@
 use cvss_rs as cvss;
-use cvss_rs::{v4_0::CvssV4, ParseError};
+use cvss_rs::v4_0::{
+    AttackVector, Automatable, CvssV4, ExploitMaturity, LintWarning, Requirement, Severity,
+};
+use cvss_rs::ParseError;
 use rstest::rstest;
 use std::str::FromStr;
 
@@ -111,7 +114,7 @@ fn test_v4_0_unknown_metric_should_error() {
 
     assert!(matches!(
         CvssV4::from_str(vector),
-        Err(cvss::ParseError::UnknownMetric { metric }) if metric == "XX"
+        Err(cvss::ParseError::UnknownMetric { metric, .. }) if metric == "XX"
     ));
 }
 
@@ -121,7 +124,7 @@ fn test_v4_0_multiple_unknown_metric_should_error_first() {
 
     assert!(matches!(
         CvssV4::from_str(vector),
-        Err(cvss::ParseError::UnknownMetric { metric }) if metric == "XX"
+        Err(cvss::ParseError::UnknownMetric { metric, .. }) if metric == "XX"
     ));
 }
 
@@ -143,6 +146,10 @@ fn test_v4_0_multiple_unknown_metric_should_error_first() {
 #[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/AR:H/AR:M", "AR")]
 #[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/S:P/S:N", "S")]
 #[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/AU:Y/AU:N", "AU")]
+#[case(
+    "CVSS:4.0/AV:N/AV:L/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    "AV"
+)]
 #[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/R:A/R:U", "R")]
 #[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/V:D/V:C", "V")]
 #[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/RE:L/RE:M", "RE")]
@@ -150,9 +157,560 @@ fn test_v4_0_multiple_unknown_metric_should_error_first() {
 fn test_v4_0_duplicate_metrics_should_error(#[case] vector: &str, #[case] expected_metric: &str) {
     let result = vector.parse::<CvssV4>();
     assert!(
-        matches!(result, Err(ParseError::DuplicateMetric { ref metric }) if metric == expected_metric),
+        matches!(result, Err(ParseError::DuplicateMetric { ref metric, .. }) if metric == expected_metric),
         "Expected DuplicateMetric error for metric '{}', but got: {:?}",
         expected_metric,
         result
     );
 }
+
+#[rstest]
+#[case("Y", Automatable::Yes)]
+#[case("N", Automatable::No)]
+#[case("X", Automatable::NotDefined)]
+fn test_v4_0_automatable_metric(#[case] value: &str, #[case] expected: Automatable) {
+    // `AU` is Automatable in v4.0, not the v2.0 Authentication metric
+    let vector =
+        format!("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/AU:{value}");
+    let cvss = CvssV4::from_str(&vector).unwrap();
+
+    assert_eq!(cvss.automatable, Some(expected));
+}
+
+#[test]
+fn test_v4_0_automatable_is_not_routed_to_v2() {
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/AU:N";
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.version(), cvss::Version::V4);
+    if let cvss::Cvss::V4(c) = cvss {
+        assert_eq!(c.automatable, Some(Automatable::No));
+    } else {
+        panic!("Expected Cvss::V4 variant");
+    }
+}
+
+#[test]
+fn test_v4_0_effective_metrics_merge_modified() {
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MAV:P/CR:L";
+    let cvss = CvssV4::from_str(vector).unwrap();
+    let effective = cvss.effective_metrics().unwrap();
+
+    assert_eq!(effective.attack_vector, AttackVector::Physical);
+    assert_eq!(cvss.attack_vector, Some(AttackVector::Network));
+    assert_eq!(effective.confidentiality_requirement, Requirement::Low);
+    // absent requirements and exploit maturity take their scoring defaults
+    assert_eq!(effective.integrity_requirement, Requirement::High);
+    assert_eq!(effective.exploit_maturity, ExploitMaturity::Attacked);
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:P/VC:N/VI:N/VA:N/SC:L/SI:L/SA:N",
+    vec![]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:P/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    vec![LintWarning::AttackRequirementsWithLowComplexity]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MAT:P",
+    vec![LintWarning::AttackRequirementsWithLowComplexity]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+    vec![LintWarning::NoImpact]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/S:P",
+    vec![LintWarning::SafetyWithoutSafetyImpact]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MAV:N/MSI:S/S:P",
+    vec![LintWarning::RedundantModifiedMetric { metric: "AV" }]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MSC:N/MSI:S/S:P",
+    vec![LintWarning::RedundantModifiedMetric { metric: "SC" }]
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    vec![]
+)]
+fn test_v4_0_lint(#[case] vector: &str, #[case] expected: Vec<LintWarning>) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+
+    assert_eq!(cvss.lint(), expected);
+}
+
+#[test]
+fn test_v4_0_top_contributors() {
+    let vector = "CVSS:4.0/AV:P/AC:H/AT:P/PR:H/UI:A/VC:H/VI:N/VA:N/SC:N/SI:N/SA:N";
+    let cvss = cvss::Cvss::from_str(vector).unwrap();
+
+    assert_eq!(cvss.top_contributors(1), vec![("VC", 1.0)]);
+}
+
+#[rstest]
+#[case("CVSS:4.0/")]
+#[case("CVSS:4.0//")]
+fn test_v4_0_prefix_only_names_first_missing_metric(#[case] vector: &str) {
+    assert_eq!(
+        CvssV4::from_str(vector),
+        Err(ParseError::MissingRequiredMetric {
+            metric: "AV".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_v4_0_prefix_without_separator_is_malformed() {
+    assert_eq!(
+        CvssV4::from_str("CVSS:4.0"),
+        Err(ParseError::MalformedVectorString)
+    );
+}
+
+#[test]
+fn test_v4_0_confidence_rises_with_threat_and_environmental_metrics() {
+    let base = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let base = cvss::Cvss::from_str(base).unwrap();
+    let full = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:A/CR:H/MAV:L";
+    let full = cvss::Cvss::from_str(full).unwrap();
+    let not_defined = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X";
+    let not_defined = cvss::Cvss::from_str(not_defined).unwrap();
+
+    assert!((base.confidence() - 0.6).abs() < 1e-9);
+    assert!(full.confidence() > base.confidence());
+    assert_eq!(not_defined.confidence(), base.confidence());
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U",
+    9.3
+)]
+#[case(
+    "CVSS:4.0/AV:L/AC:H/AT:P/PR:L/UI:A/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N/CR:L/MAV:P",
+    1.0
+)]
+fn test_v4_0_max_achievable_score(#[case] vector: &str, #[case] expected: f64) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+    let max = cvss.max_achievable_score().unwrap();
+
+    assert!(max >= cvss.calculated_base_score().unwrap());
+    assert!(max >= cvss.calculated_full_score().unwrap());
+    assert_eq!(max, expected);
+}
+
+#[test]
+fn test_v4_0_parse_lossy_valid_vector_has_no_errors() {
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let (cvss, errors) = cvss::Cvss::parse_lossy(vector);
+
+    assert!(errors.is_empty());
+    assert_eq!(cvss.unwrap().version(), cvss::Version::V4);
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+    true
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N/E:A/CR:H",
+    true
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:N/VA:N/SC:N/SI:N/SA:N/MVC:N",
+    true
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N/MSA:L",
+    false
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N",
+    false
+)]
+fn test_v4_0_is_no_impact_shortcut(#[case] vector: &str, #[case] expected: bool) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+
+    assert_eq!(cvss.is_no_impact_shortcut(), expected);
+    if expected {
+        assert_eq!(cvss.calculated_full_score(), Some(0.0));
+    } else {
+        assert_ne!(cvss.calculated_full_score(), Some(0.0));
+    }
+}
+
+#[rstest]
+#[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U/CR:L/IR:L/AR:L/MAV:P")]
+#[case("CVSS:4.0/AV:L/AC:H/AT:P/PR:L/UI:P/VC:H/VI:L/VA:N/SC:L/SI:L/SA:N/E:P/MVC:N/MSI:S")]
+fn test_v4_0_calculated_threat_score_ignores_environmental(#[case] vector: &str) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+    let threat_only = vector
+        .split("/CR:")
+        .next()
+        .unwrap()
+        .split("/MVC:")
+        .next()
+        .unwrap();
+    let expected = CvssV4::from_str(threat_only)
+        .unwrap()
+        .calculated_full_score();
+
+    assert!(expected.is_some());
+    assert_eq!(cvss.calculated_threat_score(), expected);
+    assert_ne!(cvss.calculated_full_score(), expected);
+}
+
+#[test]
+fn test_v4_0_calculated_threat_score_without_threat_is_base_score() {
+    let cvss =
+        CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MAV:P")
+            .unwrap();
+
+    assert_eq!(cvss.calculated_threat_score(), Some(9.3));
+    assert_eq!(cvss.calculated_full_score(), Some(7.0));
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+    Severity::None
+)]
+#[case(
+    "CVSS:4.0/AV:P/AC:H/AT:P/PR:H/UI:A/VC:L/VI:N/VA:N/SC:N/SI:N/SA:N",
+    Severity::Low
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:L/UI:N/VC:N/VI:L/VA:N/SC:N/SI:N/SA:N",
+    Severity::Medium
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    Severity::Critical
+)]
+fn test_v4_0_calculated_base_severity(#[case] vector: &str, #[case] expected: Severity) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+
+    assert_eq!(cvss.calculated_base_severity(), Some(expected));
+}
+
+#[test]
+fn test_v4_0_calculated_base_severity_missing_metrics() {
+    let mut cvss =
+        CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N")
+            .unwrap();
+    cvss.attack_vector = None;
+
+    assert_eq!(cvss.calculated_base_severity(), None);
+}
+
+#[test]
+fn test_v4_0_not_defined_supplemental_round_trip() {
+    let base = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    let vector = format!("{base}/S:X/AU:X/R:X/V:X/RE:X/U:X");
+    let mut cvss = CvssV4::from_str(&vector).unwrap();
+
+    assert_eq!(cvss.safety, Some(cvss::v4_0::Safety::NotDefined));
+    assert_eq!(cvss.automatable, Some(Automatable::NotDefined));
+    assert_eq!(cvss.recovery, Some(cvss::v4_0::Recovery::NotDefined));
+    assert_eq!(
+        cvss.value_density,
+        Some(cvss::v4_0::ValueDensity::NotDefined)
+    );
+    assert_eq!(
+        cvss.vulnerability_response_effort,
+        Some(cvss::v4_0::VulnerabilityResponseEffort::NotDefined)
+    );
+    assert_eq!(
+        cvss.provider_urgency,
+        Some(cvss::v4_0::ProviderUrgency::NotDefined)
+    );
+    assert_eq!(cvss.to_vector_string_verbose(), vector);
+    assert_eq!(cvss.to_string(), base);
+
+    cvss.normalize_supplemental();
+    assert_eq!(cvss.to_vector_string_verbose(), base);
+    assert_eq!(cvss.calculated_base_score(), Some(9.3));
+}
+
+#[rstest]
+#[case("000000", Some(10.0))]
+#[case("000001", Some(9.9))]
+#[case("002001", Some(9.2))]
+#[case("002000", None)]
+#[case("300000", None)]
+#[case("00000", None)]
+#[case("0000000", None)]
+#[case("00000a", None)]
+fn test_v4_0_base_value_for_macro(#[case] mv: &str, #[case] expected: Option<f64>) {
+    assert_eq!(cvss::v4_0::base_value_for_macro(mv), expected);
+}
+
+/// Shuffles `items` in place with a Fisher-Yates shuffle driven by a xorshift generator, so the
+/// permutations are reproducible without pulling in a random number crate.
+fn shuffle<T>(items: &mut [T], state: &mut u64) {
+    for i in (1..items.len()).rev() {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        items.swap(i, (*state % (i as u64 + 1)) as usize);
+    }
+}
+
+#[rstest]
+#[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N")]
+#[case("CVSS:4.0/AV:L/AC:H/AT:P/PR:L/UI:A/VC:L/VI:N/VA:H/SC:H/SI:L/SA:N/E:P")]
+#[case(
+    "CVSS:4.0/AV:A/AC:L/AT:N/PR:H/UI:P/VC:H/VI:L/VA:N/SC:L/SI:H/SA:H/E:U/CR:L/IR:H/AR:M/MAV:N/MSI:S/S:P/AU:Y"
+)]
+fn test_v4_0_score_is_independent_of_metric_order(#[case] vector: &str) {
+    let expected = CvssV4::from_str(vector).unwrap().calculated_base_score();
+    assert!(expected.is_some());
+
+    let (prefix, metrics) = vector.split_once('/').unwrap();
+    let mut components: Vec<&str> = metrics.split('/').collect();
+    let mut state = 0x9e37_79b9_7f4a_7c15;
+    for _ in 0..200 {
+        shuffle(&mut components, &mut state);
+        let shuffled = format!("{prefix}/{}", components.join("/"));
+        let cvss = CvssV4::from_str(&shuffled).unwrap();
+        assert_eq!(cvss.calculated_base_score(), expected, "{shuffled}");
+    }
+}
+
+#[test]
+fn test_v4_0_builder_matches_parsed_vector() {
+    use cvss::v4_0::{
+        AttackComplexity, AttackRequirements, Impact, PrivilegesRequired, Safety, SubsequentImpact,
+        UserInteraction,
+    };
+
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:P/CR:L/S:P";
+    let cvss = CvssV4::builder()
+        .attack_vector(AttackVector::Network)
+        .attack_complexity(AttackComplexity::Low)
+        .attack_requirements(AttackRequirements::None)
+        .privileges_required(PrivilegesRequired::None)
+        .user_interaction(UserInteraction::None)
+        .vuln_confidentiality_impact(Impact::High)
+        .vuln_integrity_impact(Impact::High)
+        .vuln_availability_impact(Impact::High)
+        .sub_confidentiality_impact(SubsequentImpact::None)
+        .sub_integrity_impact(SubsequentImpact::None)
+        .sub_availability_impact(SubsequentImpact::None)
+        .exploit_maturity(ExploitMaturity::ProofOfConcept)
+        .confidentiality_requirement(Requirement::Low)
+        .safety(Safety::Present)
+        .build()
+        .unwrap();
+    let parsed = CvssV4::from_str(vector).unwrap();
+
+    assert_eq!(cvss.vector_string, vector);
+    assert_eq!(Some(cvss.base_score), parsed.calculated_base_score());
+    assert_eq!(Some(cvss.base_severity), parsed.calculated_base_severity());
+
+    let result = CvssV4::builder()
+        .attack_vector(AttackVector::Network)
+        .build();
+    assert_eq!(
+        result.unwrap_err(),
+        ParseError::MissingRequiredMetric {
+            metric: "AC".to_string()
+        }
+    );
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N",
+    Some("000200")
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U",
+    Some("000220")
+)]
+#[case(
+    "CVSS:4.0/AV:L/AC:H/AT:P/PR:L/UI:A/VC:L/VI:N/VA:N/SC:H/SI:S/SA:N/MSI:S/CR:L",
+    Some("212001")
+)]
+#[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H", None)]
+fn test_v4_0_macro_vector(#[case] vector: &str, #[case] expected: Option<&str>) {
+    let cvss = CvssV4::from_str_lenient(vector).unwrap();
+    let macro_vector = cvss.macro_vector();
+
+    assert_eq!(macro_vector.map(|mv| mv.to_string()).as_deref(), expected);
+    if let Some(mv) = macro_vector {
+        assert!(cvss::v4_0::base_value_for_macro(&mv.to_string()).is_some());
+    }
+}
+
+#[rstest]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:A",
+    "9.3 / Critical / CVSS-BT"
+)]
+#[case(
+    "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N",
+    "0.0 / None / CVSS-B"
+)]
+fn test_v4_0_summary(#[case] vector: &str, #[case] expected: &str) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+
+    assert_eq!(cvss.summary().unwrap().to_string(), expected);
+}
+
+#[rstest]
+#[case("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N")]
+#[case("CVSS:4.0/AV:L/AC:H/AT:P/PR:L/UI:A/VC:L/VI:N/VA:H/SC:L/SI:H/SA:N")]
+#[case("CVSS:4.0/AV:A/AC:L/AT:N/PR:H/UI:P/VC:H/VI:L/VA:N/SC:N/SI:N/SA:L")]
+fn test_v4_0_score_breakdown(#[case] vector: &str) {
+    let cvss = CvssV4::from_str(vector).unwrap();
+    let breakdown = cvss.score_breakdown().unwrap();
+
+    let interpolated = breakdown.base_value - breakdown.mean_distance;
+    assert_eq!(
+        (interpolated * 10.0 + 1e-4).round() / 10.0,
+        cvss.calculated_base_score().unwrap()
+    );
+    assert_eq!(breakdown.score, cvss.calculated_base_score().unwrap());
+    assert_eq!(Some(breakdown.macro_vector), cvss.macro_vector());
+    assert!(breakdown.mean_distance >= 0.0);
+}
+
+#[test]
+fn test_v4_0_score_breakdown_no_impact() {
+    let cvss = CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N")
+        .unwrap();
+    let breakdown = cvss.score_breakdown().unwrap();
+
+    assert_eq!(breakdown.base_value, 0.0);
+    assert_eq!(breakdown.mean_distance, 0.0);
+    assert_eq!(breakdown.score, 0.0);
+}
+
+#[test]
+fn test_v4_0_score_breakdown_metrics() {
+    let cvss = CvssV4::from_str(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:H/S:P",
+    )
+    .unwrap();
+    let breakdown = cvss.score_breakdown().unwrap();
+
+    assert_eq!(
+        breakdown.metrics,
+        ["AV", "AC", "AT", "PR", "UI", "VC", "VI", "VA", "SC", "SI", "SA", "CR"]
+    );
+    assert_eq!(breakdown.macro_vector.as_tuple(), (0, 0, 0, 2, 0, 0));
+}
+
+#[test]
+fn test_v4_0_to_explicit_string() {
+    let cvss =
+        CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/U:Red")
+            .unwrap();
+
+    assert_eq!(
+        cvss.to_explicit_string(),
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:X/CR:X/IR:X/AR:X\
+         /MAV:X/MAC:X/MAT:X/MPR:X/MUI:X/MVC:X/MVI:X/MVA:X/MSC:X/MSI:X/MSA:X/U:Red"
+    );
+    assert_eq!(
+        CvssV4::from_str(&cvss.to_explicit_string())
+            .unwrap()
+            .calculated_base_score(),
+        cvss.calculated_base_score()
+    );
+
+    let partial = CvssV4::from_str_lenient("CVSS:4.0/AV:N/AC:L").unwrap();
+    let explicit = partial.to_explicit_string();
+    assert!(explicit.starts_with("CVSS:4.0/AV:N/AC:L/E:X/CR:X/"));
+    assert!(CvssV4::from_str_lenient(&explicit).is_ok());
+}
+
+#[test]
+fn test_v4_0_modified_safety_impact_sets_eq4() {
+    let base = CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:H/SA:N")
+        .unwrap();
+    let safety =
+        CvssV4::from_str("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:H/SA:N/MSI:S")
+            .unwrap();
+
+    assert_eq!(base.macro_vector().unwrap().eq4, 1);
+    assert_eq!(
+        safety.effective_metrics().unwrap().sub_integrity_impact,
+        cvss::v4_0::SubsequentImpact::Safety
+    );
+    assert_eq!(safety.macro_vector().unwrap().eq4, 0);
+    assert!(safety.calculated_full_score() > base.calculated_full_score());
+}
+
+#[test]
+fn test_v4_0_calculated_score_for_nomenclature() {
+    use cvss::v4_0::Nomenclature;
+
+    let cvss = CvssV4::from_str(
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:P/MVI:N/MVA:N",
+    )
+    .unwrap();
+
+    let scores: Vec<_> = [
+        Nomenclature::CvssB,
+        Nomenclature::CvssBE,
+        Nomenclature::CvssBT,
+        Nomenclature::CvssBTE,
+    ]
+    .iter()
+    .map(|nomenclature| cvss.calculated_score_for(nomenclature).unwrap())
+    .collect();
+
+    assert_eq!(scores, [9.3, 8.7, 8.9, 7.7]);
+
+    let (score, nomenclature) = cvss.calculated_score().unwrap();
+    assert_eq!(nomenclature, Nomenclature::from(&cvss));
+    assert_eq!(cvss.calculated_score_for(&nomenclature), Some(score));
+}
+
+#[test]
+fn test_v4_0_feature_vector() {
+    let cvss = CvssV4::from_str(
+        "CVSS:4.0/AV:N/AC:L/AT:P/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/MAV:L/MAC:X",
+    )
+    .unwrap();
+    let features = cvss.feature_vector();
+
+    assert_eq!(features.len(), CvssV4::FEATURE_COLUMNS.len());
+    assert_eq!(features[0], 1.0);
+    assert!((features[2] - 0.9).abs() < 1e-9);
+    assert_eq!(features[11], 0.0);
+    assert!((features[15] - 0.8).abs() < 1e-9);
+    assert_eq!(features[16], 0.0);
+    assert!(CvssV4::from_str_lenient("CVSS:4.0/AC:L")
+        .unwrap()
+        .feature_vector()
+        .starts_with(&[0.0, 1.0]));
+}
+
+#[test]
+fn test_v4_0_score_pseudo_metrics() {
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/baseSeverity:HIGH/baseScore:9.3";
+    let cvss = CvssV4::from_str(vector).unwrap();
+
+    assert_eq!(cvss.base_score, 9.3);
+    assert_eq!(cvss.base_severity, Severity::High);
+    assert_eq!(
+        cvss.vector_string,
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+    );
+
+    // v4.0 has no temporal score
+    let vector =
+        "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/temporalScore:9.3";
+    assert!(matches!(
+        CvssV4::from_str(vector),
+        Err(ParseError::UnknownMetric { metric, .. }) if metric == "TEMPORALSCORE"
+    ));
+}
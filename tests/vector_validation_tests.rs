@@ -1,4 +1,5 @@
 mod vector_validation_tests {
+    mod ambiguous_keys_tests;
     mod v2_tests;
     mod v3_tests;
     mod v4_tests;
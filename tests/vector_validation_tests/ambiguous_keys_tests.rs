@@ -0,0 +1,139 @@
+use cvss_rs as cvss;
+use cvss_rs::{v2_0, v3, v4_0, Cvss, ParseError};
+use rstest::rstest;
+use std::str::FromStr;
+
+const V2_BASE: &str = "AV:N/AC:L/Au:N/C:P/I:P/A:P";
+const V3_BASE: &str = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+const V4_BASE: &str = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+
+#[test]
+fn test_s_is_scope_in_v3() {
+    let cvss = Cvss::from_str("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H").unwrap();
+    let Cvss::V3_1(cvss) = cvss else {
+        panic!("Expected Cvss::V3_1 variant");
+    };
+
+    assert_eq!(cvss.scope, Some(v3::Scope::Changed));
+}
+
+#[test]
+fn test_s_is_safety_in_v4() {
+    let cvss = Cvss::from_str(&format!("{V4_BASE}/S:P")).unwrap();
+    let Cvss::V4(cvss) = cvss else {
+        panic!("Expected Cvss::V4 variant");
+    };
+
+    assert_eq!(cvss.safety, Some(v4_0::Safety::Present));
+    assert_eq!(
+        cvss.sub_availability_impact,
+        Some(v4_0::SubsequentImpact::None)
+    );
+}
+
+#[test]
+fn test_a_is_availability_in_v2_and_v3() {
+    let Cvss::V2(v2) = Cvss::from_str("AV:N/AC:L/Au:N/C:N/I:N/A:C").unwrap() else {
+        panic!("Expected Cvss::V2 variant");
+    };
+    assert_eq!(v2.availability_impact, Some(v2_0::Impact::Complete));
+
+    let Cvss::V3_1(v3) = Cvss::from_str(V3_BASE).unwrap() else {
+        panic!("Expected Cvss::V3_1 variant");
+    };
+    assert_eq!(v3.availability_impact, Some(v3::Impact::High));
+}
+
+#[test]
+fn test_e_uses_the_values_of_each_version() {
+    let Cvss::V2(v2) = Cvss::from_str(&format!("{V2_BASE}/E:POC")).unwrap() else {
+        panic!("Expected Cvss::V2 variant");
+    };
+    assert_eq!(
+        v2.exploitability,
+        Some(v2_0::Exploitability::ProofOfConcept)
+    );
+
+    let Cvss::V3_1(v3) = Cvss::from_str(&format!("{V3_BASE}/E:P")).unwrap() else {
+        panic!("Expected Cvss::V3_1 variant");
+    };
+    assert_eq!(
+        v3.exploit_code_maturity,
+        Some(v3::ExploitCodeMaturity::ProofOfConcept)
+    );
+
+    let Cvss::V4(v4) = Cvss::from_str(&format!("{V4_BASE}/E:A")).unwrap() else {
+        panic!("Expected Cvss::V4 variant");
+    };
+    assert_eq!(v4.exploit_maturity, Some(v4_0::ExploitMaturity::Attacked));
+}
+
+#[test]
+fn test_at_and_r_are_v4_metrics() {
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:P/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/R:I";
+    let Cvss::V4(v4) = Cvss::from_str(vector).unwrap() else {
+        panic!("Expected Cvss::V4 variant");
+    };
+
+    assert_eq!(
+        v4.attack_requirements,
+        Some(v4_0::AttackRequirements::Present)
+    );
+    assert_eq!(v4.recovery, Some(v4_0::Recovery::Irrecoverable));
+}
+
+/// A key that is valid in one version, but with a value only defined by another version.
+#[rstest]
+#[case("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:P/C:H/I:H/A:H", "S")]
+#[case(&format!("{V4_BASE}/S:C"), "S")]
+#[case(&format!("{V2_BASE}/E:P"), "E")]
+#[case(&format!("{V3_BASE}/E:POC"), "E")]
+#[case(&format!("{V3_BASE}/E:A"), "E")]
+#[case(&format!("{V4_BASE}/E:F"), "E")]
+#[case(&format!("{V4_BASE}/AU:S"), "AU")]
+fn test_value_of_another_version_is_invalid(#[case] vector: &str, #[case] metric: &str) {
+    assert!(matches!(
+        Cvss::from_str(vector),
+        Err(ParseError::InvalidMetricValue { metric: m, .. }) if m == metric
+    ));
+}
+
+/// A key that is only defined by another version.
+#[rstest]
+#[case(V2_BASE, "S", "U")]
+#[case(V2_BASE, "AT", "N")]
+#[case(V2_BASE, "R", "A")]
+#[case(V3_BASE, "AT", "N")]
+#[case(V3_BASE, "R", "A")]
+#[case(V3_BASE, "AU", "Y")]
+#[case(V4_BASE, "A", "H")]
+#[case(V4_BASE, "RL", "O")]
+fn test_key_of_another_version_is_unknown(
+    #[case] base: &str,
+    #[case] metric: &str,
+    #[case] value: &str,
+) {
+    let vector = format!("{base}/{metric}:{value}");
+
+    assert!(matches!(
+        Cvss::from_str(&vector),
+        Err(ParseError::UnknownMetric { metric: m, .. }) if m == metric
+    ));
+}
+
+#[test]
+fn test_registry_matches_parsers() {
+    for version in [cvss::Version::V2, cvss::Version::V3_1, cvss::Version::V4] {
+        for key in ["A", "S", "E", "R", "AU", "AT"] {
+            let base = match version {
+                cvss::Version::V2 => V2_BASE,
+                cvss::Version::V3_1 => V3_BASE,
+                _ => V4_BASE,
+            };
+            let result = Cvss::from_str(&format!("{base}/{key}:Q"));
+            let unknown = matches!(result, Err(ParseError::UnknownMetric { .. }));
+
+            assert_eq!(version.supports_metric(key), !unknown, "{version} {key}");
+        }
+    }
+}
@@ -86,3 +86,31 @@ fn test_v2_0_invalid_malformed_vector() {
         Err(cvss::ParseError::MalformedVectorString)
     ));
 }
+
+#[test]
+fn test_v2_0_missing_required_metric() {
+    let vector = "AV:N/AC:L/C:C/I:C/A:C";
+    let result = cvss::v2_0::CvssV2::from_str(vector);
+
+    assert!(matches!(
+        result,
+        Err(cvss::ParseError::MissingRequiredMetric { metric }) if metric == "Au"
+    ));
+
+    let cvss = cvss::v2_0::CvssV2::from_str_lenient(vector)
+        .expect("should parse partial v2.0 vector leniently");
+    assert_eq!(cvss.authentication, None);
+    assert_eq!(cvss.calculated_base_score(), None);
+}
+
+#[test]
+fn test_v2_0_unified_parser_lenient() {
+    let vector = "AV:N/AC:L";
+
+    assert!(matches!(
+        vector.parse::<cvss::Cvss>(),
+        Err(cvss::ParseError::MissingRequiredMetric { metric }) if metric == "Au"
+    ));
+    let cvss = cvss::Cvss::from_str_lenient(vector).expect("should parse leniently");
+    assert_eq!(cvss.version(), cvss::Version::V2);
+}
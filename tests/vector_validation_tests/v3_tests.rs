@@ -87,3 +87,30 @@ fn test_v3_invalid_malformed_vector() {
         Err(cvss::ParseError::MalformedVectorString)
     ));
 }
+
+#[test]
+fn test_v3_1_missing_required_metric() {
+    let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/C:H/I:H/A:H";
+    let result = cvss::v3::CvssV3::from_str(vector);
+
+    assert!(matches!(
+        result,
+        Err(cvss::ParseError::MissingRequiredMetric { metric }) if metric == "S"
+    ));
+
+    let cvss = cvss::v3::CvssV3::from_str_lenient(vector)
+        .expect("should parse partial v3.1 vector leniently");
+    assert_eq!(cvss.scope, None);
+    assert_eq!(cvss.calculated_base_score(), None);
+}
+
+#[test]
+fn test_v3_1_no_metrics() {
+    let vector = "CVSS:3.1/";
+
+    assert!(matches!(
+        cvss::v3::CvssV3::from_str(vector),
+        Err(cvss::ParseError::MissingRequiredMetric { metric }) if metric == "AV"
+    ));
+    assert!(cvss::v3::CvssV3::from_str_lenient(vector).is_ok());
+}
@@ -3,7 +3,7 @@ use std::str::FromStr;
 
 #[test]
 fn test_v4_0_valid_prefix() {
-    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H";
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
     let cvss = cvss::v4_0::CvssV4::from_str(vector)
         .expect("should parse valid v4.0 vector with CVSS:4.0 prefix");
 
@@ -87,3 +87,30 @@ fn test_v4_0_invalid_malformed_vector() {
         Err(cvss::ParseError::MalformedVectorString)
     ));
 }
+
+#[test]
+fn test_v4_0_missing_required_metric() {
+    let vector = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H";
+    let result = cvss::v4_0::CvssV4::from_str(vector);
+
+    assert!(matches!(
+        result,
+        Err(cvss::ParseError::MissingRequiredMetric { metric }) if metric == "SC"
+    ));
+
+    let cvss = cvss::v4_0::CvssV4::from_str_lenient(vector)
+        .expect("should parse partial v4.0 vector leniently");
+    assert_eq!(cvss.sub_confidentiality_impact, None);
+    assert_eq!(cvss.calculated_base_score(), None);
+}
+
+#[test]
+fn test_v4_0_no_metrics() {
+    let vector = "CVSS:4.0/";
+
+    assert!(matches!(
+        cvss::v4_0::CvssV4::from_str(vector),
+        Err(cvss::ParseError::MissingRequiredMetric { metric }) if metric == "AV"
+    ));
+    assert!(cvss::v4_0::CvssV4::from_str_lenient(vector).is_ok());
+}
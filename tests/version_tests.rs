@@ -0,0 +1,132 @@
+use cvss_rs::version::MetricGroup;
+use cvss_rs::Version;
+use rstest::rstest;
+
+#[rstest]
+#[case(Version::V2, "AV", true)]
+#[case(Version::V2, "Au", true)]
+#[case(Version::V2, "CDP", true)]
+#[case(Version::V2, "PR", false)]
+#[case(Version::V2, "AT", false)]
+#[case(Version::V3_0, "S", true)]
+#[case(Version::V3_1, "MPR", true)]
+#[case(Version::V3_1, "AT", false)]
+#[case(Version::V3_1, "CDP", false)]
+#[case(Version::V4, "AT", true)]
+#[case(Version::V4, "AU", true)]
+#[case(Version::V4, "MSI", true)]
+#[case(Version::V4, "MS", false)]
+#[case(Version::V4, "RC", false)]
+fn test_supports_metric(#[case] version: Version, #[case] abbr: &str, #[case] expected: bool) {
+    assert_eq!(version.supports_metric(abbr), expected);
+}
+
+#[rstest]
+#[case("2.0", Version::V2)]
+#[case("3.0", Version::V3_0)]
+#[case("3.1", Version::V3_1)]
+#[case("4.0", Version::V4)]
+#[case("V2", Version::V2)]
+#[case("V3_0", Version::V3_0)]
+#[case("V3_1", Version::V3_1)]
+#[case("V4", Version::V4)]
+fn test_version_from_str(#[case] input: &str, #[case] expected: Version) {
+    assert_eq!(input.parse::<Version>().unwrap(), expected);
+}
+
+#[rstest]
+#[case("3")]
+#[case("4.1")]
+#[case("CVSS:3.1")]
+fn test_version_from_str_invalid(#[case] input: &str) {
+    assert!(input.parse::<Version>().is_err());
+}
+
+#[rstest]
+#[case(Version::V2, "2.0")]
+#[case(Version::V3_0, "3.0")]
+#[case(Version::V3_1, "3.1")]
+#[case(Version::V4, "4.0")]
+fn test_version_display(#[case] version: Version, #[case] expected: &str) {
+    assert_eq!(version.to_string(), expected);
+}
+
+#[rstest]
+#[case(Version::V2, "CDP", Some(MetricGroup::Environmental))]
+#[case(Version::V3_1, "RL", Some(MetricGroup::Temporal))]
+#[case(Version::V4, "E", Some(MetricGroup::Threat))]
+#[case(Version::V4, "au", Some(MetricGroup::Supplemental))]
+#[case(Version::V4, "RL", None)]
+fn test_metric_group(
+    #[case] version: Version,
+    #[case] abbr: &str,
+    #[case] expected: Option<MetricGroup>,
+) {
+    assert_eq!(version.metric_group(abbr), expected);
+}
+
+#[rstest]
+#[case(Version::V2, 200)]
+#[case(Version::V3_0, 300)]
+#[case(Version::V3_1, 310)]
+#[case(Version::V4, 400)]
+fn test_version_code(#[case] version: Version, #[case] code: u16) {
+    assert_eq!(version.as_code(), code);
+    assert_eq!(Version::from_code(code), Some(version));
+}
+
+#[test]
+fn test_version_code_order_and_unknown() {
+    let codes = [Version::V2, Version::V3_0, Version::V3_1, Version::V4].map(|v| v.as_code());
+    assert!(codes.is_sorted());
+    assert_eq!(Version::from_code(0), None);
+    assert_eq!(Version::from_code(301), None);
+}
+
+#[test]
+fn test_metric_descriptions_v3_1_attack_vector() {
+    let (_, name, description) = Version::V3_1
+        .metric_descriptions()
+        .iter()
+        .find(|(abbr, _, _)| *abbr == "AV")
+        .unwrap();
+
+    assert_eq!(*name, "Attack Vector");
+    assert_eq!(
+        *description,
+        "This metric reflects the context by which vulnerability exploitation is possible."
+    );
+}
+
+#[rstest]
+#[case(Version::V2)]
+#[case(Version::V3_0)]
+#[case(Version::V3_1)]
+#[case(Version::V4)]
+fn test_metric_descriptions_cover_all_metrics(#[case] version: Version) {
+    let described: Vec<&str> = version
+        .metric_descriptions()
+        .iter()
+        .map(|(abbr, _, _)| *abbr)
+        .collect();
+
+    assert_eq!(described, version.metrics().collect::<Vec<_>>());
+    assert!(version
+        .metric_descriptions()
+        .iter()
+        .all(|(_, name, description)| !name.is_empty() && description.ends_with(['.', '"', '?'])));
+}
+
+#[rstest]
+#[case(Version::V2, true, Some(Version::V3_1))]
+#[case(Version::V3_0, true, Some(Version::V3_1))]
+#[case(Version::V3_1, false, Some(Version::V4))]
+#[case(Version::V4, false, None)]
+fn test_deprecation(
+    #[case] version: Version,
+    #[case] deprecated: bool,
+    #[case] successor: Option<Version>,
+) {
+    assert_eq!(version.is_deprecated(), deprecated);
+    assert_eq!(version.successor(), successor);
+}
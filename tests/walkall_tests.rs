@@ -1,8 +1,8 @@
 use anyhow::{anyhow, bail};
-use cvss_rs::{v2_0::CvssV2, v3::CvssV3, v4_0::CvssV4};
+use cvss_rs::ingest::try_scores_from_cve_json;
+use cvss_rs::{v2_0::CvssV2, v3::CvssV3, v4_0::CvssV4, Cvss};
 use indicatif::{ParallelProgressIterator, ProgressBar, ProgressStyle};
 use rayon::prelude::*;
-use serde::Deserialize;
 use std::env;
 use std::ffi::OsStr;
 use std::fs;
@@ -12,33 +12,6 @@ use std::str::FromStr;
 use std::sync::Mutex;
 use walkdir::WalkDir;
 
-#[derive(Deserialize)]
-struct CveRoot {
-    containers: CnaContainers,
-}
-
-#[derive(Deserialize)]
-struct CnaContainers {
-    cna: Cna,
-}
-
-#[derive(Deserialize)]
-struct Cna {
-    metrics: Option<Vec<Metric>>,
-}
-
-#[derive(Deserialize)]
-struct Metric {
-    #[serde(rename = "cvssV3_1")]
-    cvss_v3_1: Option<CvssV3>,
-    #[serde(rename = "cvssV3_0")]
-    cvss_v3_0: Option<CvssV3>,
-    #[serde(rename = "cvssV2_0")]
-    cvss_v2_0: Option<CvssV2>,
-    #[serde(rename = "cvssV4_0")]
-    cvss_v4_0: Option<CvssV4>,
-}
-
 #[test]
 fn test_walkall() -> anyhow::Result<()> {
     let source = match env::var("CVE_BASE_DIR") {
@@ -467,256 +440,254 @@ fn verify_with_redhat(vector: &str) -> Option<f64> {
 
 fn process(path: &Path) -> anyhow::Result<ProcessResult> {
     let content = fs::read(path)?;
-    let cve: CveRoot = serde_json::from_slice(&content)
+    let scores = try_scores_from_cve_json(&content)
         .map_err(|e| anyhow!("Failed to deserialize CVE: {}", e))?;
 
     let mut stats = ScoreStats::default();
     let mut mismatches = Vec::new();
 
-    if let Some(metrics) = cve.containers.cna.metrics {
-        for metric in metrics {
-            // Process V2.0
-            if let Some(v2) = &metric.cvss_v2_0 {
-                stats.v2_total += 1;
-                let json_score = v2.base_score;
+    for cvss in &scores {
+        // Process V2.0
+        if let Cvss::V2(v2) = cvss {
+            stats.v2_total += 1;
+            let json_score = v2.base_score;
 
-                // Validate JSON score range
-                if !(0.0..=10.0).contains(&json_score) {
-                    bail!("Invalid V2.0 base_score: {}", json_score);
-                }
+            // Validate JSON score range
+            if !(0.0..=10.0).contains(&json_score) {
+                bail!("Invalid V2.0 base_score: {}", json_score);
+            }
 
-                // Parse vector and calculate score
-                match CvssV2::from_str(&v2.vector_string) {
-                    Ok(parsed) => {
-                        match parsed.calculated_base_score() {
-                            Some(calculated) => {
-                                // calculated_base_score() already rounds to 1 decimal place
-                                let diff = (json_score - calculated).abs();
-
-                                if diff < 0.05 {
-                                    stats.v2_matches += 1;
-                                } else {
-                                    let redhat_score = verify_with_redhat(&v2.vector_string);
-                                    mismatches.push(ScoreMismatch {
-                                        version: "V2.0".to_string(),
-                                        vector: v2.vector_string.clone(),
-                                        expected_score: json_score,
-                                        calculated_score: calculated,
-                                        redhat_score,
-                                        base_score: None,
-                                        temporal_score: None,
-                                        environmental_score: None,
-                                    });
-                                }
-                            }
-                            None => {
-                                // Score calculation returned None
+            // Parse vector and calculate score
+            match CvssV2::from_str(&v2.vector_string) {
+                Ok(parsed) => {
+                    match parsed.calculated_base_score() {
+                        Some(calculated) => {
+                            // calculated_base_score() already rounds to 1 decimal place
+                            let diff = (json_score - calculated).abs();
+
+                            if diff < 0.05 {
+                                stats.v2_matches += 1;
+                            } else {
+                                let redhat_score = verify_with_redhat(&v2.vector_string);
                                 mismatches.push(ScoreMismatch {
                                     version: "V2.0".to_string(),
-                                    vector: format!("{} (score calc failed)", v2.vector_string),
+                                    vector: v2.vector_string.clone(),
                                     expected_score: json_score,
-                                    calculated_score: 0.0,
-                                    redhat_score: None,
+                                    calculated_score: calculated,
+                                    redhat_score,
                                     base_score: None,
                                     temporal_score: None,
                                     environmental_score: None,
                                 });
                             }
                         }
-                    }
-                    Err(_) => {
-                        // Parsing failed
-                        mismatches.push(ScoreMismatch {
-                            version: "V2.0".to_string(),
-                            vector: format!("{} (parse failed)", v2.vector_string),
-                            expected_score: json_score,
-                            calculated_score: 0.0,
-                            redhat_score: None,
-                            base_score: None,
-                            temporal_score: None,
-                            environmental_score: None,
-                        });
-                    }
-                }
-            }
-
-            // Process V3.0
-            if let Some(v3_0) = &metric.cvss_v3_0 {
-                stats.v3_0_total += 1;
-                let json_score = v3_0.base_score;
-
-                if !(0.0..=10.0).contains(&json_score) {
-                    bail!("Invalid V3.0 base_score: {}", json_score);
-                }
-
-                if let Ok(parsed) = CvssV3::from_str(&v3_0.vector_string) {
-                    // Try base, temporal, and environmental scores to handle CVE database inconsistency
-                    // (some include temporal/environmental metrics in baseScore, some don't)
-                    let base_only = parsed.calculated_base_score();
-                    let with_temporal = parsed.calculated_temporal_score();
-                    let with_environmental = parsed.calculated_environmental_score();
-
-                    // Use the score that matches (prefer base > temporal > environmental)
-                    let calculated = if let Some(base) = base_only {
-                        if (json_score - base).abs() < 0.05 {
-                            Some(base)
-                        } else if let Some(temporal) = with_temporal {
-                            if (json_score - temporal).abs() < 0.05 {
-                                Some(temporal)
-                            } else if let Some(env) = with_environmental {
-                                Some(env)
-                            } else {
-                                Some(temporal)
-                            }
-                        } else if let Some(env) = with_environmental {
-                            Some(env)
-                        } else {
-                            Some(base)
-                        }
-                    } else {
-                        with_temporal.or(with_environmental)
-                    };
-
-                    if let Some(calculated) = calculated {
-                        let diff = (json_score - calculated).abs();
-
-                        if diff < 0.05 {
-                            stats.v3_0_matches += 1;
-                        } else {
-                            // Red Hat calculator returns base score, so compare with our base_score
-                            let redhat_score =
-                                base_only.and_then(|_| verify_with_redhat(&v3_0.vector_string));
+                        None => {
+                            // Score calculation returned None
                             mismatches.push(ScoreMismatch {
-                                version: "V3.0".to_string(),
-                                vector: v3_0.vector_string.clone(),
+                                version: "V2.0".to_string(),
+                                vector: format!("{} (score calc failed)", v2.vector_string),
                                 expected_score: json_score,
-                                calculated_score: calculated,
-                                redhat_score,
-                                base_score: base_only,
-                                temporal_score: with_temporal,
-                                environmental_score: with_environmental,
+                                calculated_score: 0.0,
+                                redhat_score: None,
+                                base_score: None,
+                                temporal_score: None,
+                                environmental_score: None,
                             });
                         }
                     }
                 }
+                Err(_) => {
+                    // Parsing failed
+                    mismatches.push(ScoreMismatch {
+                        version: "V2.0".to_string(),
+                        vector: format!("{} (parse failed)", v2.vector_string),
+                        expected_score: json_score,
+                        calculated_score: 0.0,
+                        redhat_score: None,
+                        base_score: None,
+                        temporal_score: None,
+                        environmental_score: None,
+                    });
+                }
             }
+        }
 
-            // Process V3.1
-            if let Some(v3_1) = &metric.cvss_v3_1 {
-                stats.v3_1_total += 1;
-                let json_score = v3_1.base_score;
+        // Process V3.0
+        if let Cvss::V3_0(v3_0) = cvss {
+            stats.v3_0_total += 1;
+            let json_score = v3_0.base_score;
 
-                if !(0.0..=10.0).contains(&json_score) {
-                    bail!("Invalid V3.1 base_score: {}", json_score);
-                }
+            if !(0.0..=10.0).contains(&json_score) {
+                bail!("Invalid V3.0 base_score: {}", json_score);
+            }
 
-                if let Ok(parsed) = CvssV3::from_str(&v3_1.vector_string) {
-                    // Try base, temporal, and environmental scores to handle CVE database inconsistency
-                    // (some include temporal/environmental metrics in baseScore, some don't)
-                    let base_only = parsed.calculated_base_score();
-                    let with_temporal = parsed.calculated_temporal_score();
-                    let with_environmental = parsed.calculated_environmental_score();
-
-                    // Use the score that matches (prefer base > temporal > environmental)
-                    let calculated = if let Some(base) = base_only {
-                        if (json_score - base).abs() < 0.05 {
-                            Some(base)
-                        } else if let Some(temporal) = with_temporal {
-                            if (json_score - temporal).abs() < 0.05 {
-                                Some(temporal)
-                            } else if let Some(env) = with_environmental {
-                                Some(env)
-                            } else {
-                                Some(temporal)
-                            }
+            if let Ok(parsed) = CvssV3::from_str(&v3_0.vector_string) {
+                // Try base, temporal, and environmental scores to handle CVE database inconsistency
+                // (some include temporal/environmental metrics in baseScore, some don't)
+                let base_only = parsed.calculated_base_score();
+                let with_temporal = parsed.calculated_temporal_score();
+                let with_environmental = parsed.calculated_environmental_score();
+
+                // Use the score that matches (prefer base > temporal > environmental)
+                let calculated = if let Some(base) = base_only {
+                    if (json_score - base).abs() < 0.05 {
+                        Some(base)
+                    } else if let Some(temporal) = with_temporal {
+                        if (json_score - temporal).abs() < 0.05 {
+                            Some(temporal)
                         } else if let Some(env) = with_environmental {
                             Some(env)
                         } else {
-                            Some(base)
+                            Some(temporal)
                         }
+                    } else if let Some(env) = with_environmental {
+                        Some(env)
                     } else {
-                        with_temporal.or(with_environmental)
-                    };
+                        Some(base)
+                    }
+                } else {
+                    with_temporal.or(with_environmental)
+                };
 
-                    if let Some(calculated) = calculated {
-                        let diff = (json_score - calculated).abs();
+                if let Some(calculated) = calculated {
+                    let diff = (json_score - calculated).abs();
 
-                        if diff < 0.05 {
-                            stats.v3_1_matches += 1;
-                        } else {
-                            // Red Hat calculator returns base score, so compare with our base_score
-                            let redhat_score =
-                                base_only.and_then(|_| verify_with_redhat(&v3_1.vector_string));
-                            mismatches.push(ScoreMismatch {
-                                version: "V3.1".to_string(),
-                                vector: v3_1.vector_string.clone(),
-                                expected_score: json_score,
-                                calculated_score: calculated,
-                                redhat_score,
-                                base_score: base_only,
-                                temporal_score: with_temporal,
-                                environmental_score: with_environmental,
-                            });
-                        }
+                    if diff < 0.05 {
+                        stats.v3_0_matches += 1;
+                    } else {
+                        // Red Hat calculator returns base score, so compare with our base_score
+                        let redhat_score =
+                            base_only.and_then(|_| verify_with_redhat(&v3_0.vector_string));
+                        mismatches.push(ScoreMismatch {
+                            version: "V3.0".to_string(),
+                            vector: v3_0.vector_string.clone(),
+                            expected_score: json_score,
+                            calculated_score: calculated,
+                            redhat_score,
+                            base_score: base_only,
+                            temporal_score: with_temporal,
+                            environmental_score: with_environmental,
+                        });
                     }
                 }
             }
+        }
 
-            // Process V4.0
-            if let Some(v4) = &metric.cvss_v4_0 {
-                stats.v4_total += 1;
-                let json_score = v4.base_score;
+        // Process V3.1
+        if let Cvss::V3_1(v3_1) = cvss {
+            stats.v3_1_total += 1;
+            let json_score = v3_1.base_score;
 
-                if !(0.0..=10.0).contains(&json_score) {
-                    bail!("Invalid V4.0 base_score: {}", json_score);
-                }
+            if !(0.0..=10.0).contains(&json_score) {
+                bail!("Invalid V3.1 base_score: {}", json_score);
+            }
 
-                // Calculate with our implementation - try BOTH base score and full score
-                // to handle CVE database inconsistency (some include E in baseScore, some don't)
-                let (score_without_e, score_with_e) =
-                    if let Ok(parsed) = CvssV4::from_str(&v4.vector_string) {
-                        (
-                            parsed.calculated_base_score(),
-                            parsed.calculated_full_score(),
-                        )
-                    } else {
-                        (None, None)
-                    };
-
-                // Use the score that matches (prefer without E for backwards compatibility)
-                let calculated = if let Some(score_without_e) = score_without_e {
-                    if (json_score - score_without_e).abs() < 0.05 {
-                        Some(score_without_e)
-                    } else if let Some(score_with_e) = score_with_e {
-                        Some(score_with_e)
+            if let Ok(parsed) = CvssV3::from_str(&v3_1.vector_string) {
+                // Try base, temporal, and environmental scores to handle CVE database inconsistency
+                // (some include temporal/environmental metrics in baseScore, some don't)
+                let base_only = parsed.calculated_base_score();
+                let with_temporal = parsed.calculated_temporal_score();
+                let with_environmental = parsed.calculated_environmental_score();
+
+                // Use the score that matches (prefer base > temporal > environmental)
+                let calculated = if let Some(base) = base_only {
+                    if (json_score - base).abs() < 0.05 {
+                        Some(base)
+                    } else if let Some(temporal) = with_temporal {
+                        if (json_score - temporal).abs() < 0.05 {
+                            Some(temporal)
+                        } else if let Some(env) = with_environmental {
+                            Some(env)
+                        } else {
+                            Some(temporal)
+                        }
+                    } else if let Some(env) = with_environmental {
+                        Some(env)
                     } else {
-                        Some(score_without_e)
+                        Some(base)
                     }
                 } else {
-                    score_with_e
+                    with_temporal.or(with_environmental)
                 };
 
-                // Compare our implementation with JSON
                 if let Some(calculated) = calculated {
                     let diff = (json_score - calculated).abs();
+
                     if diff < 0.05 {
-                        stats.v4_matches += 1;
+                        stats.v3_1_matches += 1;
                     } else {
-                        // Red Hat calculator doesn't support V4.0
+                        // Red Hat calculator returns base score, so compare with our base_score
+                        let redhat_score =
+                            base_only.and_then(|_| verify_with_redhat(&v3_1.vector_string));
                         mismatches.push(ScoreMismatch {
-                            version: "V4.0".to_string(),
-                            vector: v4.vector_string.clone(),
+                            version: "V3.1".to_string(),
+                            vector: v3_1.vector_string.clone(),
                             expected_score: json_score,
                             calculated_score: calculated,
-                            redhat_score: None,
-                            base_score: None,
-                            temporal_score: None,
-                            environmental_score: None,
+                            redhat_score,
+                            base_score: base_only,
+                            temporal_score: with_temporal,
+                            environmental_score: with_environmental,
                         });
                     }
                 }
             }
         }
+
+        // Process V4.0
+        if let Cvss::V4(v4) = cvss {
+            stats.v4_total += 1;
+            let json_score = v4.base_score;
+
+            if !(0.0..=10.0).contains(&json_score) {
+                bail!("Invalid V4.0 base_score: {}", json_score);
+            }
+
+            // Calculate with our implementation - try BOTH base score and full score
+            // to handle CVE database inconsistency (some include E in baseScore, some don't)
+            let (score_without_e, score_with_e) =
+                if let Ok(parsed) = CvssV4::from_str(&v4.vector_string) {
+                    (
+                        parsed.calculated_base_score(),
+                        parsed.calculated_full_score(),
+                    )
+                } else {
+                    (None, None)
+                };
+
+            // Use the score that matches (prefer without E for backwards compatibility)
+            let calculated = if let Some(score_without_e) = score_without_e {
+                if (json_score - score_without_e).abs() < 0.05 {
+                    Some(score_without_e)
+                } else if let Some(score_with_e) = score_with_e {
+                    Some(score_with_e)
+                } else {
+                    Some(score_without_e)
+                }
+            } else {
+                score_with_e
+            };
+
+            // Compare our implementation with JSON
+            if let Some(calculated) = calculated {
+                let diff = (json_score - calculated).abs();
+                if diff < 0.05 {
+                    stats.v4_matches += 1;
+                } else {
+                    // Red Hat calculator doesn't support V4.0
+                    mismatches.push(ScoreMismatch {
+                        version: "V4.0".to_string(),
+                        vector: v4.vector_string.clone(),
+                        expected_score: json_score,
+                        calculated_score: calculated,
+                        redhat_score: None,
+                        base_score: None,
+                        temporal_score: None,
+                        environmental_score: None,
+                    });
+                }
+            }
+        }
     }
 
     Ok(ProcessResult { stats, mismatches })